@@ -3,13 +3,31 @@ use serde::Deserialize;
 #[cfg(feature = "mssql")]
 use async_std::net::TcpStream;
 #[cfg(feature = "mysql")]
-use mysql_async::Pool;
+use mysql_async::{Conn, Opts};
+#[cfg(feature = "sqlite")]
+use rusqlite::OpenFlags;
+#[cfg(feature = "sqlite")]
+use std::sync::{Arc, Mutex, PoisonError};
 #[cfg(feature = "mssql")]
 use tiberius::{AuthMethod, Config};
 #[cfg(feature = "postgres")]
-use tokio_postgres::{Client, NoTls};
+use tokio::io::{AsyncRead, AsyncWrite};
+#[cfg(feature = "postgres")]
+use tokio_postgres::{CancelToken, Client, Connection, NoTls};
 
+#[cfg(all(feature = "postgres", not(feature = "postgres-tls")))]
+use crate::datasources::DatasourceProblem;
+#[cfg(feature = "mysql")]
+use crate::datasources::MySQLAuth;
+#[cfg(feature = "postgres")]
+use crate::datasources::PostgresAuth;
+#[cfg(feature = "mssql")]
+use crate::datasources::SqlServerAuth;
+#[cfg(feature = "postgres")]
+use crate::datasources::SslMode;
 use crate::datasources::{Auth, DatasourceConfig};
+#[cfg(feature = "mock")]
+use crate::mock::MockDatabase;
 
 /// Represents the current supported databases by Canyon
 #[derive(Deserialize, Debug, Eq, PartialEq, Clone, Copy)]
@@ -23,6 +41,26 @@ pub enum DatabaseType {
     #[serde(alias = "mysql")]
     #[cfg(feature = "mysql")]
     MySQL,
+    #[serde(alias = "sqlite")]
+    #[cfg(feature = "sqlite")]
+    Sqlite,
+}
+
+impl DatabaseType {
+    /// The port where the servers of this database listen by default, or [`None`]
+    /// for the embedded databases
+    pub fn default_port(&self) -> Option<u16> {
+        match self {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => Some(5432),
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => Some(1433),
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => Some(3306),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => None,
+        }
+    }
 }
 
 impl From<&Auth> for DatabaseType {
@@ -34,6 +72,10 @@ impl From<&Auth> for DatabaseType {
             crate::datasources::Auth::SqlServer(_) => DatabaseType::SqlServer,
             #[cfg(feature = "mysql")]
             crate::datasources::Auth::MySQL(_) => DatabaseType::MySQL,
+            #[cfg(feature = "sqlite")]
+            crate::datasources::Auth::Sqlite(_) => DatabaseType::Sqlite,
+            #[cfg(feature = "mock")]
+            crate::datasources::Auth::Mock(database) => database.dialect(),
         }
     }
 }
@@ -45,22 +87,144 @@ pub struct PostgreSqlConnection {
     // pub connection: Connection<Socket, NoTlsStream>, // TODO Hold it, or not to hold it... that's the question!
 }
 
+/// Moves the connection half of a new `PostgreSQL` connection to its own task, which
+/// performs the communication with the server, and returns the client half.
+///
+/// The error that ends the connection isn't reported from the task: the client is
+/// closed along with it, so its pending and later queries fail, and the pool discards it
+#[cfg(feature = "postgres")]
+fn spawn_postgres_connection<S, T>((client, connection): (Client, Connection<S, T>)) -> Client
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+    T: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    tokio::spawn(async move {
+        let _ = connection.await;
+    });
+
+    client
+}
+
 /// A connection with a `SqlServer` database
 #[cfg(feature = "mssql")]
 pub struct SqlServerConnection {
-    pub client: &'static mut tiberius::Client<TcpStream>,
+    pub client: tiberius::Client<TcpStream>,
 }
 
 /// A connection with a `Mysql` database
 #[cfg(feature = "mysql")]
 pub struct MysqlConnection {
-    pub client: Pool,
+    pub client: Conn,
+}
+
+/// A connection with a `SQLite` database.
+///
+/// SQLite is embedded in the application, so the statements are run by the
+/// application itself instead of being sent to a server. The client of [`rusqlite`]
+/// can't be used from several threads at once, so it's only reached through a lock
+#[cfg(feature = "sqlite")]
+pub struct SqliteConnection {
+    client: Arc<Mutex<rusqlite::Connection>>,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteConnection {
+    /// Runs the given task over the client of the connection in the threads of the runtime
+    /// meant for blocking work, since SQLite blocks the thread that runs its statements.
+    /// A statement that panicked doesn't leave the client unusable, so the lock is taken
+    /// even if it's poisoned
+    pub async fn run_blocking<F, R>(&self, task: F) -> R
+    where
+        F: FnOnce(&mut rusqlite::Connection) -> R + Send + 'static,
+        R: Send + 'static,
+    {
+        let client = Arc::clone(&self.client);
+        let task = tokio::task::spawn_blocking(move || {
+            task(&mut client.lock().unwrap_or_else(PoisonError::into_inner))
+        });
+
+        match task.await {
+            Ok(result) => result,
+            Err(error) if error.is_panic() => std::panic::resume_unwind(error.into_panic()),
+            Err(error) => panic!("The SQLite statement didn't run: {error}"),
+        }
+    }
+}
+
+// The `unsafe impl`s of `DatabaseConnection` must not be what makes a SQLite
+// connection shareable between threads
+#[cfg(feature = "sqlite")]
+const _: fn() = || {
+    fn assert_send_sync<T: Send + Sync>() {}
+    assert_send_sync::<SqliteConnection>();
+};
+
+/// A connection with an in-memory [`MockDatabase`], shared by all the connections of its pool
+#[cfg(feature = "mock")]
+pub struct MockConnection {
+    pub database: MockDatabase,
+}
+
+/// Cancels the statement that a connection is running, from outside of the task that
+/// awaits it. It's taken from the connection before sending the statement, since the
+/// connection is borrowed until the statement finishes
+pub struct QueryCanceller {
+    #[cfg_attr(not(any(feature = "postgres", feature = "mysql")), allow(dead_code))]
+    datasource: DatasourceConfig,
+    target: CancelTarget,
+}
+
+enum CancelTarget {
+    /// The key of the connection, sent by a new connection to the server
+    #[cfg(feature = "postgres")]
+    Postgres(CancelToken),
+    /// The id of the connection, whose query is killed from another connection
+    #[cfg(feature = "mysql")]
+    MySQL(u32),
+    /// The databases that can only abort the statement by closing its connection, like
+    /// SQL Server, since its driver can't send an attention signal. SQLite interrupts the
+    /// statement by itself, with the timeout given to its launcher
+    Disconnect,
+}
+
+impl QueryCanceller {
+    /// Asks the database to cancel the statement. The connection must be closed afterwards,
+    /// since it may be left in the middle of a response. Closing it is all it takes for the
+    /// databases without a way to cancel a statement
+    pub async fn cancel(self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self.target {
+            #[cfg(feature = "postgres")]
+            CancelTarget::Postgres(token) => match self.datasource.properties.sslmode {
+                SslMode::Disable => token.cancel_query(NoTls).await?,
+                #[cfg(feature = "postgres-tls")]
+                _ => {
+                    let connector =
+                        crate::tls::postgres_tls_connector(&self.datasource.properties)?;
+                    token.cancel_query(connector).await?
+                }
+                #[cfg(not(feature = "postgres-tls"))]
+                _ => return Err(DatasourceProblem::TlsNotEnabled.to_string().into()),
+            },
+            #[cfg(feature = "mysql")]
+            CancelTarget::MySQL(connection_id) => {
+                use mysql_async::prelude::Queryable;
+                let mut killer = DatabaseConnection::connect(&self.datasource).await?;
+                killer
+                    .mysql_connection()
+                    .client
+                    .query_drop(format!("KILL QUERY {connection_id}"))
+                    .await?;
+            }
+            CancelTarget::Disconnect => {}
+        }
+        Ok(())
+    }
 }
 
 /// The Canyon database connection handler. When the client's program
 /// starts, Canyon gets the information about the desired datasources,
-/// process them and generates a pool of 1 to 1 database connection for
-/// every datasource defined.
+/// process them and generates a [`crate::pool::DatabaseConnectionPool`]
+/// of these connections for every datasource defined.
 pub enum DatabaseConnection {
     #[cfg(feature = "postgres")]
     Postgres(PostgreSqlConnection),
@@ -68,51 +232,71 @@ pub enum DatabaseConnection {
     SqlServer(SqlServerConnection),
     #[cfg(feature = "mysql")]
     MySQL(MysqlConnection),
+    #[cfg(feature = "sqlite")]
+    Sqlite(SqliteConnection),
+    #[cfg(feature = "mock")]
+    Mock(MockConnection),
 }
 
 unsafe impl Send for DatabaseConnection {}
 unsafe impl Sync for DatabaseConnection {}
 
 impl DatabaseConnection {
+    /// Opens a new connection to the database of the datasource, with the settings of
+    /// its `properties.session` already applied
     pub async fn new(
         datasource: &DatasourceConfig,
     ) -> Result<DatabaseConnection, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-        match datasource.get_db_type() {
+        let mut connection = Self::connect(datasource).await?;
+        let statements = datasource
+            .properties
+            .session
+            .statements(datasource.get_db_type());
+        for statement in &statements {
+            connection.execute_setting(statement).await?;
+        }
+
+        Ok(connection)
+    }
+
+    async fn connect(
+        datasource: &DatasourceConfig,
+    ) -> Result<DatabaseConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        // The auth of a datasource selects its database, so every arm has its own credentials
+        match &datasource.auth {
             #[cfg(feature = "postgres")]
-            DatabaseType::PostgreSql => {
-                let (username, password) = match &datasource.auth {
-                    crate::datasources::Auth::Postgres(postgres_auth) => match postgres_auth {
-                        crate::datasources::PostgresAuth::Basic { username, password } => {
-                            (username.as_str(), password.as_str())
-                        }
-                    },
-                    #[cfg(feature = "mssql")]
-                    crate::datasources::Auth::SqlServer(_) => {
-                        panic!("Found SqlServer auth configuration for a PostgreSQL datasource")
-                    }
-                    #[cfg(feature = "mysql")]
-                    crate::datasources::Auth::MySQL(_) => {
-                        panic!("Found MySql auth configuration for a PostgreSQL datasource")
+            Auth::Postgres(PostgresAuth::Basic { username, password }) => {
+                let mut config: tokio_postgres::Config =
+                    crate::connection_url::postgres_options(&datasource.properties).parse()?;
+                config
+                    .user(username)
+                    .password(password)
+                    .dbname(&datasource.properties.db_name);
+                match &datasource.properties.socket {
+                    // The socket is looked for as `.s.PGSQL.<port>` within the directory
+                    #[cfg(unix)]
+                    Some(socket) => config.host_path(socket),
+                    #[cfg(not(unix))]
+                    Some(_) => {
+                        return Err("The Unix sockets aren't supported on this platform".into())
                     }
+                    None => config.host(&datasource.properties.host),
                 };
-                let (new_client, new_connection) = tokio_postgres::connect(
-                    &format!(
-                        "postgres://{user}:{pswd}@{host}:{port}/{db}",
-                        user = username,
-                        pswd = password,
-                        host = datasource.properties.host,
-                        port = datasource.properties.port.unwrap_or_default(),
-                        db = datasource.properties.db_name
-                    )[..],
-                    NoTls,
-                )
-                .await?;
-
-                tokio::spawn(async move {
-                    if let Err(e) = new_connection.await {
-                        eprintln!("An error occurred while trying to connect to the PostgreSQL database: {e}");
+                if let Some(port) = datasource.port() {
+                    config.port(port);
+                }
+                config.ssl_mode(crate::tls::postgres_ssl_mode(datasource.properties.sslmode));
+
+                let new_client = match datasource.properties.sslmode {
+                    SslMode::Disable => spawn_postgres_connection(config.connect(NoTls).await?),
+                    #[cfg(feature = "postgres-tls")]
+                    _ => {
+                        let connector = crate::tls::postgres_tls_connector(&datasource.properties)?;
+                        spawn_postgres_connection(config.connect(connector).await?)
                     }
-                });
+                    #[cfg(not(feature = "postgres-tls"))]
+                    _ => return Err(DatasourceProblem::TlsNotEnabled.to_string().into()),
+                };
 
                 Ok(DatabaseConnection::Postgres(PostgreSqlConnection {
                     client: new_client,
@@ -120,99 +304,242 @@ impl DatabaseConnection {
                 }))
             }
             #[cfg(feature = "mssql")]
-            DatabaseType::SqlServer => {
-                let mut config = Config::new();
+            Auth::SqlServer(sql_server_auth) => {
+                let mut config = if datasource.properties.options.is_empty() {
+                    Config::new()
+                } else {
+                    let options = datasource
+                        .properties
+                        .options
+                        .iter()
+                        .map(|(key, value)| format!("{key}={value}"))
+                        .collect::<Vec<_>>()
+                        .join(";");
+                    Config::from_ado_string(&options)?
+                };
 
                 config.host(&datasource.properties.host);
-                config.port(datasource.properties.port.unwrap_or_default());
+                if let Some(port) = datasource.port() {
+                    config.port(port);
+                }
                 config.database(&datasource.properties.db_name);
+                if let Some(name) = &datasource.properties.session.application_name {
+                    config.application_name(name);
+                }
 
                 // Using SQL Server authentication.
-                config.authentication(match &datasource.auth {
-                    #[cfg(feature = "postgres")]
-                    crate::datasources::Auth::Postgres(_) => {
-                        panic!("Found PostgreSQL auth configuration for a SqlServer database")
-                    }
-                    crate::datasources::Auth::SqlServer(sql_server_auth) => match sql_server_auth {
-                        crate::datasources::SqlServerAuth::Basic { username, password } => {
-                            AuthMethod::sql_server(username, password)
-                        }
-                        crate::datasources::SqlServerAuth::Integrated => AuthMethod::Integrated,
-                    },
-                    #[cfg(feature = "mysql")]
-                    crate::datasources::Auth::MySQL(_) => {
-                        panic!("Found PostgreSQL auth configuration for a SqlServer database")
+                config.authentication(match sql_server_auth {
+                    SqlServerAuth::Basic { username, password } => {
+                        AuthMethod::sql_server(username, password)
                     }
+                    SqlServerAuth::Integrated => AuthMethod::Integrated,
                 });
 
-                // on production, it is not a good idea to do this. We should upgrade
-                // Canyon in future versions to allow the user take care about this
-                // configuration
-                config.trust_cert();
+                crate::tls::configure_sqlserver_tls(&mut config, &datasource.properties)?;
 
                 // Taking the address from the configuration, using async-std's
                 // TcpStream to connect to the server.
-                let tcp = TcpStream::connect(config.get_addr())
-                    .await
-                    .expect("Error instantiating the SqlServer TCP Stream");
+                let tcp = TcpStream::connect(config.get_addr()).await?;
 
                 // We'll disable the Nagle algorithm. Buffering is handled
                 // internally with a `Sink`.
-                tcp.set_nodelay(true)
-                    .expect("Error in the SqlServer `nodelay` config");
+                tcp.set_nodelay(true)?;
 
                 // Handling TLS, login and other details related to the SQL Server.
-                let client = tiberius::Client::connect(config, tcp).await;
+                let client = tiberius::Client::connect(config, tcp).await?;
 
                 Ok(DatabaseConnection::SqlServer(SqlServerConnection {
-                    client: Box::leak(Box::new(
-                        client.expect("A failure happened connecting to the database"),
-                    )),
+                    client,
                 }))
             }
             #[cfg(feature = "mysql")]
-            DatabaseType::MySQL => {
-                let (user, password) = match &datasource.auth {
-                    #[cfg(feature = "mssql")]
-                    crate::datasources::Auth::SqlServer(_) => {
-                        panic!("Found SqlServer auth configuration for a PostgreSQL datasource")
-                    }
-                    #[cfg(feature = "postgres")]
-                    crate::datasources::Auth::Postgres(_) => {
-                        panic!("Found MySql auth configuration for a PostgreSQL datasource")
-                    }
-                    #[cfg(feature = "mysql")]
-                    crate::datasources::Auth::MySQL(mysql_auth) => match mysql_auth {
-                        crate::datasources::MySQLAuth::Basic { username, password } => {
-                            (username, password)
-                        }
-                    },
-                };
-
-                //TODO add options to optionals params in url
+            Auth::MySQL(MySQLAuth::Basic { username, password }) => {
+                use crate::connection_url::percent_encode;
 
-                let url = format!(
-                    "mysql://{}:{}@{}:{}/{}",
-                    user,
-                    password,
-                    datasource.properties.host,
-                    datasource.properties.port.unwrap_or_default(),
-                    datasource.properties.db_name
+                // The URL needs a host, although it's ignored when the socket is given
+                let host = match datasource.properties.socket {
+                    Some(_) => "localhost",
+                    None => &datasource.properties.host,
+                };
+                let mut url = format!(
+                    "mysql://{}:{}@{}",
+                    percent_encode(username),
+                    percent_encode(password),
+                    if host.contains(':') {
+                        format!("[{host}]")
+                    } else {
+                        host.to_string()
+                    }
                 );
-                let mysql_connection = Pool::from_url(url)?;
+                if let Some(port) = datasource.port() {
+                    url.push_str(&format!(":{port}"));
+                }
+                url.push_str(&format!(
+                    "/{}",
+                    percent_encode(&datasource.properties.db_name)
+                ));
+                let options = datasource
+                    .properties
+                    .options
+                    .iter()
+                    .map(|(key, value)| (key.as_str(), value))
+                    .chain(
+                        datasource
+                            .properties
+                            .socket
+                            .as_ref()
+                            .map(|socket| ("socket", socket)),
+                    )
+                    .map(|(key, value)| {
+                        format!("{}={}", percent_encode(key), percent_encode(value))
+                    })
+                    .collect::<Vec<_>>();
+                if !options.is_empty() {
+                    url.push_str(&format!("?{}", options.join("&")));
+                }
+                let mysql_connection = Conn::new(Opts::from_url(&url)?).await?;
 
                 Ok(DatabaseConnection::MySQL(MysqlConnection {
                     client: { mysql_connection },
                 }))
             }
+            #[cfg(feature = "sqlite")]
+            Auth::Sqlite(sqlite_auth) => {
+                let client = if sqlite_auth.is_in_memory() {
+                    // Every `:memory:` connection opens its own empty database, so the
+                    // connections of the pool share a named in-memory one, which lives
+                    // while any of them is open
+                    rusqlite::Connection::open_with_flags(
+                        format!("file:{}?mode=memory&cache=shared", datasource.name),
+                        OpenFlags::default() | OpenFlags::SQLITE_OPEN_URI,
+                    )?
+                } else {
+                    let client = rusqlite::Connection::open(&sqlite_auth.path)?;
+                    // Readers don't wait for the writers of the other connections
+                    client.pragma_update_and_check(None, "journal_mode", "WAL", |_| Ok(()))?;
+                    client
+                };
+                // SQLite only enforces the foreign keys when asked to, per connection
+                client.pragma_update(None, "foreign_keys", "ON")?;
+
+                Ok(DatabaseConnection::Sqlite(SqliteConnection {
+                    client: Arc::new(Mutex::new(client)),
+                }))
+            }
+            #[cfg(feature = "mock")]
+            Auth::Mock(database) => Ok(DatabaseConnection::Mock(MockConnection {
+                database: database.clone(),
+            })),
+        }
+    }
+
+    /// Runs a statement that changes a setting of the session, without results
+    async fn execute_setting(
+        &mut self,
+        statement: &str,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(conn) => conn.client.batch_execute(statement).await?,
+            #[cfg(feature = "mssql")]
+            DatabaseConnection::SqlServer(conn) => {
+                // Sent as a batch, since the settings changed by `sp_executesql` only last
+                // until the end of its own statement
+                conn.client
+                    .simple_query(statement)
+                    .await?
+                    .into_results()
+                    .await?;
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseConnection::MySQL(conn) => {
+                use mysql_async::prelude::Queryable;
+                conn.client.query_drop(statement).await?
+            }
+            #[cfg(feature = "sqlite")]
+            DatabaseConnection::Sqlite(conn) => {
+                let statement = statement.to_string();
+                conn.run_blocking(move |client| client.execute_batch(&statement))
+                    .await?
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::Mock(_) => {}
+        }
+        Ok(())
+    }
+
+    /// Takes what's needed to cancel the statements that this connection of the given
+    /// datasource will run. See [`QueryCanceller`]
+    pub fn query_canceller(&self, datasource: &DatasourceConfig) -> QueryCanceller {
+        let target = match self {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(conn) => {
+                CancelTarget::Postgres(conn.client.cancel_token())
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseConnection::MySQL(conn) => CancelTarget::MySQL(conn.client.id()),
+            #[allow(unreachable_patterns)]
+            _ => CancelTarget::Disconnect,
+        };
+
+        QueryCanceller {
+            datasource: datasource.clone(),
+            target,
+        }
+    }
+
+    /// Whether the connection is known to be closed, without asking the database. Only
+    /// `PostgreSQL` knows it, from the task that drives its connection
+    pub fn is_closed(&self) -> bool {
+        match self {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(conn) => conn.client.is_closed(),
+            #[allow(unreachable_patterns)]
+            _ => false,
+        }
+    }
+
+    /// Makes a round trip to the database, to check that the connection still works
+    pub async fn ping(&mut self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        match self {
+            #[cfg(feature = "postgres")]
+            DatabaseConnection::Postgres(conn) => {
+                conn.client.simple_query("SELECT 1").await?;
+            }
+            #[cfg(feature = "mssql")]
+            DatabaseConnection::SqlServer(conn) => {
+                conn.client
+                    .simple_query("SELECT 1")
+                    .await?
+                    .into_results()
+                    .await?;
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseConnection::MySQL(conn) => {
+                use mysql_async::prelude::Queryable;
+                conn.client.ping().await?
+            }
+            #[cfg(feature = "sqlite")]
+            DatabaseConnection::Sqlite(conn) => {
+                conn.run_blocking(|client| client.query_row("SELECT 1", [], |_| Ok(())))
+                    .await?
+            }
+            #[cfg(feature = "mock")]
+            DatabaseConnection::Mock(_) => {}
         }
+        Ok(())
     }
 
     #[cfg(feature = "postgres")]
     pub fn postgres_connection(&self) -> &PostgreSqlConnection {
         match self {
             DatabaseConnection::Postgres(conn) => conn,
-            #[cfg(all(feature = "postgres", feature = "mssql", feature = "mysql"))]
+            #[cfg(any(
+                feature = "mssql",
+                feature = "mysql",
+                feature = "sqlite",
+                feature = "mock"
+            ))]
             _ => panic!(),
         }
     }
@@ -221,16 +548,54 @@ impl DatabaseConnection {
     pub fn sqlserver_connection(&mut self) -> &mut SqlServerConnection {
         match self {
             DatabaseConnection::SqlServer(conn) => conn,
-            #[cfg(all(feature = "postgres", feature = "mssql", feature = "mysql"))]
+            #[cfg(any(
+                feature = "postgres",
+                feature = "mysql",
+                feature = "sqlite",
+                feature = "mock"
+            ))]
             _ => panic!(),
         }
     }
 
     #[cfg(feature = "mysql")]
-    pub fn mysql_connection(&self) -> &MysqlConnection {
+    pub fn mysql_connection(&mut self) -> &mut MysqlConnection {
         match self {
             DatabaseConnection::MySQL(conn) => conn,
-            #[cfg(all(feature = "postgres", feature = "mssql", feature = "mysql"))]
+            #[cfg(any(
+                feature = "postgres",
+                feature = "mssql",
+                feature = "sqlite",
+                feature = "mock"
+            ))]
+            _ => panic!(),
+        }
+    }
+
+    #[cfg(feature = "sqlite")]
+    pub fn sqlite_connection(&self) -> &SqliteConnection {
+        match self {
+            DatabaseConnection::Sqlite(conn) => conn,
+            #[cfg(any(
+                feature = "postgres",
+                feature = "mssql",
+                feature = "mysql",
+                feature = "mock"
+            ))]
+            _ => panic!(),
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    pub fn mock_connection(&self) -> &MockConnection {
+        match self {
+            DatabaseConnection::Mock(conn) => conn,
+            #[cfg(any(
+                feature = "postgres",
+                feature = "mssql",
+                feature = "mysql",
+                feature = "sqlite"
+            ))]
             _ => panic!(),
         }
     }
@@ -318,5 +683,22 @@ mod database_connection_handler {
                 DatabaseType::MySQL
             );
         }
+
+        #[cfg(feature = "sqlite")]
+        {
+            const CONFIG_FILE_MOCK_ALT_SQLITE: &str = r#"
+                [canyon_sql]
+                datasources = [
+                    {name = 'SqliteDS', auth = { sqlite = { path = ":memory:" } }, properties.migrations='disabled' }
+                ]
+            "#;
+
+            let config: CanyonSqlConfig = toml::from_str(CONFIG_FILE_MOCK_ALT_SQLITE)
+                .expect("A failure happened retrieving the [canyon_sql] section");
+            assert_eq!(
+                config.canyon_sql.datasources[0].get_db_type(),
+                DatabaseType::Sqlite
+            );
+        }
     }
 }
@@ -0,0 +1,174 @@
+//! Resolution of the values of the configuration file that aren't written literally on it.
+//!
+//! Any string value may reference environment variables, as `${DB_USER}`, or as
+//! `${DB_HOST:-localhost}` to fall back to a default value when the variable is unset
+//! or empty. A literal `$` is written as `$$`.
+//!
+//! Any table may also replace its `password` by a `password_file`, whose content, without
+//! the trailing line break, is taken as the password. That's the way to read the secrets
+//! mounted as files by Docker or Kubernetes:
+//!
+//! ```toml
+//! [canyon_sql.datasources.auth]
+//! postgresql = { basic = { username = '${DB_USER:-postgres}', password_file = '/run/secrets/db' } }
+//! ```
+use std::{env, fs};
+
+use toml::Value;
+
+use crate::datasources::ConfigError;
+
+/// Replaces, recursively, the environment variables referenced by the strings of the
+/// given value and the `password_file` keys of its tables
+pub(crate) fn resolve(value: &mut Value) -> Result<(), ConfigError> {
+    match value {
+        Value::String(s) => *s = interpolate(s)?,
+        Value::Array(values) => {
+            for value in values {
+                resolve(value)?;
+            }
+        }
+        Value::Table(table) => {
+            for (_, value) in table.iter_mut() {
+                resolve(value)?;
+            }
+
+            if let Some(password_file) = table.remove("password_file") {
+                if table.contains_key("password") {
+                    return Err(ConfigError::PasswordAndPasswordFile);
+                }
+                let path = match password_file {
+                    Value::String(path) => path,
+                    other => return Err(ConfigError::InvalidPasswordFile(other.to_string())),
+                };
+                let password = fs::read_to_string(&path)
+                    .map_err(|source| ConfigError::PasswordFile { path, source })?;
+                table.insert(
+                    "password".to_string(),
+                    Value::String(password.trim_end_matches(['\n', '\r']).to_string()),
+                );
+            }
+        }
+        _ => {}
+    }
+
+    Ok(())
+}
+
+/// Replaces the `${VAR}` and `${VAR:-default}` placeholders of a single string
+fn interpolate(value: &str) -> Result<String, ConfigError> {
+    let mut resolved = String::with_capacity(value.len());
+    let mut rest = value;
+
+    while let Some(start) = rest.find('$') {
+        resolved.push_str(&rest[..start]);
+        rest = &rest[start..];
+
+        if let Some(after) = rest.strip_prefix("$$") {
+            resolved.push('$');
+            rest = after;
+        } else if let Some(after) = rest.strip_prefix("${") {
+            let end = after
+                .find('}')
+                .ok_or_else(|| ConfigError::InvalidPlaceholder(value.to_string()))?;
+            let (variable, default) = match after[..end].split_once(":-") {
+                Some((variable, default)) => (variable, Some(default)),
+                None => (&after[..end], None),
+            };
+            if variable.is_empty()
+                || !variable
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || c == '_')
+            {
+                return Err(ConfigError::InvalidPlaceholder(value.to_string()));
+            }
+
+            match (env::var(variable).ok().filter(|v| !v.is_empty()), default) {
+                (Some(v), _) => resolved.push_str(&v),
+                (None, Some(default)) => resolved.push_str(default),
+                (None, None) => return Err(ConfigError::MissingVariable(variable.to_string())),
+            }
+            rest = &after[end + 1..];
+        } else {
+            resolved.push('$');
+            rest = &rest[1..];
+        }
+    }
+    resolved.push_str(rest);
+
+    Ok(resolved)
+}
+
+#[cfg(test)]
+mod interpolation_tests {
+    use super::*;
+
+    #[test]
+    fn placeholders_are_replaced_by_the_environment() {
+        env::set_var("CANYON_TEST_INTERPOLATION_USER", "triforce");
+        env::remove_var("CANYON_TEST_INTERPOLATION_UNSET");
+
+        assert_eq!(
+            interpolate("postgres://${CANYON_TEST_INTERPOLATION_USER}@host").unwrap(),
+            "postgres://triforce@host"
+        );
+        assert_eq!(
+            interpolate("${CANYON_TEST_INTERPOLATION_UNSET:-localhost}:${CANYON_TEST_INTERPOLATION_UNSET:-}").unwrap(),
+            "localhost:"
+        );
+        assert_eq!(interpolate("pa$$word$").unwrap(), "pa$word$");
+    }
+
+    #[test]
+    fn errors_name_the_missing_variable() {
+        env::remove_var("CANYON_TEST_INTERPOLATION_MISSING");
+
+        let error = interpolate("${CANYON_TEST_INTERPOLATION_MISSING}").unwrap_err();
+        assert!(matches!(
+            &error,
+            ConfigError::MissingVariable(variable) if variable == "CANYON_TEST_INTERPOLATION_MISSING"
+        ));
+        assert!(error
+            .to_string()
+            .contains("CANYON_TEST_INTERPOLATION_MISSING"));
+
+        assert!(matches!(
+            interpolate("${UNCLOSED"),
+            Err(ConfigError::InvalidPlaceholder(_))
+        ));
+        assert!(matches!(
+            interpolate("${NOT A NAME}"),
+            Err(ConfigError::InvalidPlaceholder(_))
+        ));
+    }
+
+    #[test]
+    fn password_file_replaces_the_password() {
+        let path = env::temp_dir().join("canyon_test_interpolation_password");
+        fs::write(&path, "s3cr3t\n").unwrap();
+
+        let mut value: Value = toml::from_str(&format!(
+            "basic = {{ username = 'postgres', password_file = {:?} }}",
+            path.to_str().unwrap()
+        ))
+        .unwrap();
+        resolve(&mut value).unwrap();
+        fs::remove_file(&path).unwrap();
+
+        assert_eq!(value["basic"]["password"].as_str(), Some("s3cr3t"));
+        assert!(value["basic"].get("password_file").is_none());
+
+        let mut both: Value =
+            toml::from_str("basic = { password = 'a', password_file = 'b' }").unwrap();
+        assert!(matches!(
+            resolve(&mut both),
+            Err(ConfigError::PasswordAndPasswordFile)
+        ));
+
+        let mut number: Value = toml::from_str("basic = { password_file = 42 }").unwrap();
+        assert!(matches!(
+            resolve(&mut number),
+            Err(ConfigError::InvalidPasswordFile(value)) if value == "42"
+        ));
+    }
+}
@@ -4,6 +4,8 @@ pub extern crate futures;
 pub extern crate lazy_static;
 #[cfg(feature = "mysql")]
 pub extern crate mysql_async;
+#[cfg(feature = "sqlite")]
+pub extern crate rusqlite;
 #[cfg(feature = "mssql")]
 pub extern crate tiberius;
 pub extern crate tokio;
@@ -12,36 +14,106 @@ pub extern crate tokio_postgres;
 pub extern crate tokio_util;
 
 pub mod canyon_database_connector;
+mod connection_url;
 pub mod datasources;
+pub mod interpolation;
+#[cfg(feature = "mock")]
+pub mod mock;
+pub mod pool;
+pub mod runtime;
+pub mod session;
+#[cfg(any(feature = "postgres", feature = "mssql"))]
+mod tls;
 
-use std::fs;
 use std::path::PathBuf;
+use std::sync::{Arc, OnceLock, RwLock};
+use std::time::Duration;
+use std::{env, fs};
 
-use crate::datasources::{CanyonSqlConfig, DatasourceConfig};
-use canyon_database_connector::DatabaseConnection;
+use crate::canyon_database_connector::DatabaseType;
+use crate::datasources::{CanyonSqlConfig, ConfigError, DatasourceConfig};
+use crate::pool::{DatabaseConnectionPool, InitConnectionsError, PoolError, PooledConnection};
 use indexmap::IndexMap;
 use lazy_static::lazy_static;
-use tokio::sync::{Mutex, MutexGuard};
+use tokio::runtime::Runtime;
 use walkdir::WalkDir;
 
 lazy_static! {
-    pub static ref CANYON_TOKIO_RUNTIME: tokio::runtime::Runtime =
-        tokio::runtime::Runtime::new()  // TODO Make the config with the builder
-            .expect("Failed initializing the Canyon-SQL Tokio Runtime");
+    pub static ref DATASOURCES: Vec<DatasourceConfig> =
+        canyon_config().canyon_sql.datasources.clone();
+    pub static ref DATABASE_POOLS: RwLock<IndexMap<String, Arc<DatabaseConnectionPool>>> =
+        RwLock::new(IndexMap::new());
+}
 
-    static ref RAW_CONFIG_FILE: String = fs::read_to_string(find_canyon_config_file())
-        .expect("Error opening or reading the Canyon configuration file");
-    static ref CONFIG_FILE: CanyonSqlConfig = toml::from_str(RAW_CONFIG_FILE.as_str())
-        .expect("Error generating the configuration for Canyon-SQL");
+/// The environment variable with the path of the configuration file, when it isn't
+/// a `canyon*.toml` file of the working directory
+pub const CANYON_CONFIG_ENV: &str = "CANYON_CONFIG";
 
-    pub static ref DATASOURCES: Vec<DatasourceConfig> =
-        CONFIG_FILE.canyon_sql.datasources.clone();
+static CANYON_CONFIG: OnceLock<CanyonSqlConfig> = OnceLock::new();
+
+static CANYON_RUNTIME: OnceLock<Runtime> = OnceLock::new();
 
-    pub static ref CACHED_DATABASE_CONN: Mutex<IndexMap<&'static str, DatabaseConnection>> =
-        Mutex::new(IndexMap::new());
+/// Sets the configuration of Canyon from the code, instead of loading it from the
+/// configuration file. It must be called before anything uses the datasources
+pub fn set_config(config: CanyonSqlConfig) -> Result<(), ConfigError> {
+    CANYON_CONFIG
+        .set(config)
+        .map_err(|_| ConfigError::AlreadyInitialized)
+}
+
+/// The configuration of Canyon, as set by [`set_config`], or otherwise loaded from
+/// the configuration file the first time it's needed
+pub fn canyon_config() -> &'static CanyonSqlConfig {
+    CANYON_CONFIG.get_or_init(|| {
+        let path = find_canyon_config_file();
+        let raw_config = fs::read_to_string(&path).unwrap_or_else(|e| {
+            panic!("Error reading the Canyon configuration file {path:?}: {e}")
+        });
+        CanyonSqlConfig::parse(&raw_config)
+            .unwrap_or_else(|e| panic!("Error generating the configuration for Canyon-SQL: {e}"))
+    })
+}
+
+/// The `query_timeout` of the configuration, which bounds the statements that don't set
+/// their own timeout. The configuration is only looked up once it's loaded, so there's no
+/// default timeout for the applications that only use registered datasources
+pub fn default_query_timeout() -> Option<Duration> {
+    CANYON_CONFIG
+        .get()
+        .and_then(|config| config.canyon_sql.query_timeout())
+}
+
+/// The Tokio runtime of Canyon, built with the `runtime` settings of its configuration
+/// the first time that it's needed. See [`runtime::RuntimeProperties`]
+pub fn canyon_runtime() -> &'static Runtime {
+    CANYON_RUNTIME.get_or_init(|| {
+        canyon_config()
+            .canyon_sql
+            .runtime
+            .build()
+            .unwrap_or_else(|e| panic!("Failed initializing the Canyon-SQL Tokio Runtime: {e}"))
+    })
+}
+
+/// Configures Canyon with the given configuration and initializes the connection
+/// pools of its datasources. The alternative to `#[canyon_sql::main]` for the
+/// applications that build their configuration in the code.
+///
+/// Fails with a [`ConfigError`] if Canyon is already configured, or with an
+/// [`InitConnectionsError`] if some datasources can't connect to their databases
+pub async fn init(
+    config: CanyonSqlConfig,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    set_config(config)?;
+    init_connections_cache().await?;
+    Ok(())
 }
 
 fn find_canyon_config_file() -> PathBuf {
+    if let Some(path) = env::var_os(CANYON_CONFIG_ENV) {
+        return PathBuf::from(path);
+    }
+
     for e in WalkDir::new(".")
         .max_depth(2)
         .into_iter()
@@ -56,54 +128,177 @@ fn find_canyon_config_file() -> PathBuf {
         }
     }
 
-    panic!()
+    panic!(
+        "No canyon*.toml configuration file was found in the working directory. \
+        Set the {CANYON_CONFIG_ENV} environment variable with its path, \
+        or configure Canyon from the code with `init`"
+    )
 }
 
-/// Convenient free function to initialize a kind of connection pool based on the datasources present defined
-/// in the configuration file.
+/// Convenient free function to initialize the connection pools of the datasources defined
+/// in the configuration of Canyon.
 ///
 /// This avoids Canyon to create a new connection to the database on every query, potentially avoiding bottlenecks
-/// coming from the instantiation of that new conn every time.
+/// coming from the instantiation of that new conn every time. Every datasource gets its own
+/// [`DatabaseConnectionPool`], sized by its `properties.pool` settings, and the `min_size` connections
+/// of every pool are opened here, unless the datasource is `lazy`.
+///
+/// A datasource that can't connect doesn't prevent the others from being initialized. Its pool is
+/// registered anyway, and it's reported by the returned [`InitConnectionsError`]
 ///
 /// Note: We noticed with the integration tests that the [`tokio_postgres`] crate (PostgreSQL) is able to work in an async environment
 /// with a new connection per query without no problem, but the [`tiberius`] crate (MSSQL) suffers a lot when it has continuous
 /// statements with multiple queries, like and insert followed by a find by id to check if the insert query has done its
 /// job done.
-pub async fn init_connections_cache() {
+pub async fn init_connections_cache() -> Result<(), InitConnectionsError> {
+    let mut failures = Vec::new();
+
     for datasource in DATASOURCES.iter() {
-        CACHED_DATABASE_CONN.lock().await.insert(
-            &datasource.name,
-            DatabaseConnection::new(datasource)
-                .await
-                .unwrap_or_else(|_| {
-                    panic!(
-                        "Error pooling a new connection for the datasource: {:?}",
-                        datasource.name
-                    )
-                }),
-        );
+        let pool = DatabaseConnectionPool::new(datasource.clone());
+        if !datasource.properties.lazy {
+            if let Err(error) = pool.warm_up().await {
+                failures.push((datasource.name.clone(), error));
+            }
+        }
+
+        DATABASE_POOLS
+            .write()
+            .expect("The registry of connection pools is poisoned")
+            .insert(datasource.name.clone(), pool);
+    }
+
+    if failures.is_empty() {
+        Ok(())
+    } else {
+        Err(InitConnectionsError { failures })
     }
 }
 
-pub fn get_database_connection<'a>(
+/// Registers a new datasource while the application is running, like the database of a
+/// new tenant, and opens the `min_size` connections of its pool unless it's `lazy`.
+///
+/// The datasource is validated first, its `replicas` must be already registered, and its
+/// name can't be taken by another registered datasource. From then on, its name can be
+/// used as any datasource of the configuration
+pub async fn register_datasource(
+    datasource: DatasourceConfig,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let mut problems = datasource.validate();
+    problems.extend(datasource.validate_replicas(&list_datasources()));
+    if !problems.is_empty() {
+        return Err(ConfigError::InvalidDatasources(
+            problems
+                .into_iter()
+                .map(|problem| (datasource.name.clone(), problem))
+                .collect(),
+        )
+        .into());
+    }
+    if get_connection_pool(&datasource.name).is_ok() {
+        return Err(ConfigError::DuplicatedDatasource(datasource.name).into());
+    }
+
+    let pool = DatabaseConnectionPool::new(datasource);
+    if !pool.datasource().properties.lazy {
+        pool.warm_up().await?;
+    }
+
+    let mut pools = DATABASE_POOLS
+        .write()
+        .expect("The registry of connection pools is poisoned");
+    let name = &pool.datasource().name;
+    // Another registration of the same name may have finished while this one was connecting
+    if pools.contains_key(name) {
+        return Err(ConfigError::DuplicatedDatasource(name.clone()).into());
+    }
+    pools.insert(name.clone(), pool);
+
+    Ok(())
+}
+
+/// Removes a registered datasource, returning its configuration. Its connections in use
+/// stay open until they're released, and then they're closed along with its pool
+pub fn unregister_datasource(datasource_name: &str) -> Result<DatasourceConfig, PoolError> {
+    DATABASE_POOLS
+        .write()
+        .expect("The registry of connection pools is poisoned")
+        .shift_remove(datasource_name)
+        .map(|pool| pool.datasource().clone())
+        .ok_or_else(|| PoolError::DatasourceNotFound(datasource_name.to_string()))
+}
+
+/// The datasources that currently have a connection pool, the ones of the configuration
+/// first, followed by the ones registered with [`register_datasource`]
+pub fn list_datasources() -> Vec<DatasourceConfig> {
+    DATABASE_POOLS
+        .read()
+        .expect("The registry of connection pools is poisoned")
+        .values()
+        .map(|pool| pool.datasource().clone())
+        .collect()
+}
+
+/// Retrieves the connection pool of the datasource with the given name, or the one
+/// of the first registered datasource if the name is empty, which is the first one
+/// defined in the configuration
+pub fn get_connection_pool(
     datasource_name: &str,
-    guarded_cache: &'a mut MutexGuard<IndexMap<&str, DatabaseConnection>>,
-) -> &'a mut DatabaseConnection {
-    if datasource_name.is_empty() {
-        guarded_cache
-            .get_mut(
-                DATASOURCES
-                    .first()
-                    .expect("We didn't found any valid datasource configuration. Check your `canyon.toml` file")
-                    .name
-                    .as_str()
-            ).unwrap_or_else(|| panic!("No default datasource found. Check your `canyon.toml` file"))
+) -> Result<Arc<DatabaseConnectionPool>, PoolError> {
+    let pools = DATABASE_POOLS
+        .read()
+        .expect("The registry of connection pools is poisoned");
+
+    let pool = if datasource_name.is_empty() {
+        pools.first().map(|(_, pool)| pool)
     } else {
-        guarded_cache.get_mut(datasource_name)
-            .unwrap_or_else(||
-                panic!("Canyon couldn't find a datasource in the pool with the argument provided: {datasource_name}")
-            )
+        pools.get(datasource_name)
+    };
+    pool.cloned()
+        .ok_or_else(|| PoolError::DatasourceNotFound(datasource_name.to_string()))
+}
+
+/// Checks that the datasource with the given name, or the default one if the name is
+/// empty, can reach its database, reconnecting its pool if needed
+pub async fn ping(
+    datasource_name: &str,
+) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+    get_connection_pool(datasource_name)?.ping().await
+}
+
+/// The type of the database of a registered datasource, or of the default one if
+/// the name is empty
+pub fn get_database_type(datasource_name: &str) -> Result<DatabaseType, PoolError> {
+    get_connection_pool(datasource_name).map(|pool| pool.datasource().get_db_type())
+}
+
+/// Checks out a connection from the pool of the given datasource, or from the default
+/// one if the name is empty. The connection is returned to its pool once dropped
+pub async fn get_database_connection(
+    datasource_name: &str,
+) -> Result<PooledConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    get_connection_pool(datasource_name)?.acquire().await
+}
+
+/// Checks out a connection for a read-only operation from the given datasource, or from
+/// the default one if the name is empty.
+///
+/// When the datasource has `replicas`, they take turns to serve the reads, skipping the
+/// ones that can't be reached or that were unregistered. A replica is tried once, without
+/// the retries of its pool, and the ones that fail to connect are skipped for a while.
+/// The datasource itself, the primary, serves the read when none of its replicas can
+pub async fn get_read_connection(
+    datasource_name: &str,
+) -> Result<PooledConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+    let primary = get_connection_pool(datasource_name)?;
+    for replica in primary.replicas_round_robin() {
+        let Ok(pool) = get_connection_pool(replica) else {
+            continue;
+        };
+        if let Ok(conn) = pool.try_acquire().await {
+            return Ok(conn);
+        }
     }
+    primary.acquire().await
 }
 
 pub fn get_database_config<'a>(
@@ -0,0 +1,382 @@
+//! An in-memory database for the unit tests of the code that uses Canyon.
+//!
+//! A [`MockDatabase`] records every statement sent to it, along with the values of its
+//! parameters, and answers them with the rows scripted by the test, in order. It takes
+//! the dialect of one of the supported databases, so the query builders generate the
+//! same SQL that they would send to a real one.
+//!
+//! ```ignore
+//! let database = MockDatabase::new(DatabaseType::PostgreSql);
+//! register_datasource(DatasourceConfig::mock("mock", database.clone())).await?;
+//!
+//! database.push_rows([MockRow::new([("id", 1.into()), ("name", "LCK".into())])]);
+//! let leagues = League::find_all_datasource("mock").await?;
+//! assert_eq!(database.statements()[0].sql, "SELECT * FROM league");
+//! ```
+use std::collections::VecDeque;
+use std::error::Error;
+use std::fmt;
+use std::sync::{Arc, Mutex, MutexGuard};
+
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, NaiveTime, Utc};
+
+use crate::canyon_database_connector::DatabaseType;
+
+/// A value sent as a parameter of a statement, or returned in a [`MockRow`]
+#[derive(Debug, Clone, PartialEq)]
+pub enum MockValue {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Text(String),
+    Date(NaiveDate),
+    Time(NaiveTime),
+    DateTime(NaiveDateTime),
+    DateTimeTz(DateTime<FixedOffset>),
+}
+
+macro_rules! impl_from_for_mock_value {
+    ($($ty:ty => $variant:ident $(as $target:ty)?),* $(,)?) => {
+        $(
+            impl From<$ty> for MockValue {
+                fn from(value: $ty) -> Self {
+                    MockValue::$variant(value $(as $target)?)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_for_mock_value!(
+    bool => Bool,
+    i16 => Int as i64,
+    i32 => Int as i64,
+    i64 => Int,
+    f32 => Float as f64,
+    f64 => Float,
+    String => Text,
+    NaiveDate => Date,
+    NaiveTime => Time,
+    NaiveDateTime => DateTime,
+    DateTime<FixedOffset> => DateTimeTz,
+);
+
+impl From<&str> for MockValue {
+    fn from(value: &str) -> Self {
+        MockValue::Text(value.to_string())
+    }
+}
+
+impl From<DateTime<Utc>> for MockValue {
+    fn from(value: DateTime<Utc>) -> Self {
+        MockValue::DateTimeTz(value.fixed_offset())
+    }
+}
+
+impl<T: Into<MockValue>> From<Option<T>> for MockValue {
+    fn from(value: Option<T>) -> Self {
+        value.map_or(MockValue::Null, Into::into)
+    }
+}
+
+/// The types that can be retrieved from a [`MockValue`], which are the ones of the
+/// fields of the entities
+pub trait FromMockValue: Sized {
+    /// Converts the value, or returns [`None`] if it's of another type
+    fn from_mock_value(value: &MockValue) -> Option<Self>;
+}
+
+macro_rules! impl_from_mock_value {
+    ($($ty:ty => $variant:ident),* $(,)?) => {
+        $(
+            impl FromMockValue for $ty {
+                fn from_mock_value(value: &MockValue) -> Option<Self> {
+                    match value {
+                        MockValue::$variant(value) => (*value).try_into().ok(),
+                        _ => None,
+                    }
+                }
+            }
+        )*
+    };
+}
+
+impl_from_mock_value!(
+    bool => Bool,
+    i16 => Int,
+    i32 => Int,
+    i64 => Int,
+    NaiveDate => Date,
+    NaiveTime => Time,
+    NaiveDateTime => DateTime,
+    DateTime<FixedOffset> => DateTimeTz,
+);
+
+impl FromMockValue for f32 {
+    fn from_mock_value(value: &MockValue) -> Option<Self> {
+        f64::from_mock_value(value).map(|value| value as f32)
+    }
+}
+
+impl FromMockValue for f64 {
+    fn from_mock_value(value: &MockValue) -> Option<Self> {
+        match value {
+            MockValue::Float(value) => Some(*value),
+            MockValue::Int(value) => Some(*value as f64),
+            _ => None,
+        }
+    }
+}
+
+impl FromMockValue for String {
+    fn from_mock_value(value: &MockValue) -> Option<Self> {
+        match value {
+            MockValue::Text(value) => Some(value.clone()),
+            _ => None,
+        }
+    }
+}
+
+impl FromMockValue for DateTime<Utc> {
+    fn from_mock_value(value: &MockValue) -> Option<Self> {
+        DateTime::<FixedOffset>::from_mock_value(value).map(|value| value.with_timezone(&Utc))
+    }
+}
+
+impl<T: FromMockValue> FromMockValue for Option<T> {
+    fn from_mock_value(value: &MockValue) -> Option<Self> {
+        match value {
+            MockValue::Null => Some(None),
+            value => T::from_mock_value(value).map(Some),
+        }
+    }
+}
+
+/// A row scripted as a result of a [`MockDatabase`], made of the named values of its columns
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockRow {
+    columns: Vec<String>,
+    values: Vec<MockValue>,
+}
+
+impl MockRow {
+    /// Creates a row with the given pairs of column name and value, in order
+    pub fn new<S: Into<String>>(columns: impl IntoIterator<Item = (S, MockValue)>) -> Self {
+        let (columns, values) = columns
+            .into_iter()
+            .map(|(name, value)| (name.into(), value))
+            .unzip();
+        Self { columns, values }
+    }
+
+    /// The names of the columns of the row
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The values of the row, in the same order as its columns
+    pub fn values(&self) -> &[MockValue] {
+        &self.values
+    }
+
+    /// Retrieves the value of the column with the given name, converted into `T`
+    pub fn try_get<T: FromMockValue>(&self, column: &str) -> Result<T, MockError> {
+        let index = self
+            .columns
+            .iter()
+            .position(|name| name == column)
+            .ok_or_else(|| MockError(format!("the row has no `{column}` column")))?;
+
+        self.try_get_at(index)
+    }
+
+    /// Retrieves the value of the column at the given position, converted into `T`
+    pub fn try_get_at<T: FromMockValue>(&self, index: usize) -> Result<T, MockError> {
+        let value = self
+            .values
+            .get(index)
+            .ok_or_else(|| MockError(format!("the row has no column at the position {index}")))?;
+
+        T::from_mock_value(value).ok_or_else(|| {
+            MockError(format!(
+                "the value {value:?} can't be retrieved as a `{}`",
+                std::any::type_name::<T>()
+            ))
+        })
+    }
+}
+
+/// A statement received by a [`MockDatabase`]
+#[derive(Debug, Clone, PartialEq)]
+pub struct MockStatement {
+    /// The SQL of the statement, as generated by Canyon
+    pub sql: String,
+    /// The values of the parameters bound to the statement
+    pub params: Vec<MockValue>,
+}
+
+/// An error of a [`MockDatabase`], scripted by the test or raised when a row can't
+/// be retrieved as expected
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MockError(pub String);
+
+impl fmt::Display for MockError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Mock database error: {}", self.0)
+    }
+}
+
+impl Error for MockError {}
+
+#[derive(Default)]
+struct MockState {
+    statements: Vec<MockStatement>,
+    results: VecDeque<Result<Vec<MockRow>, MockError>>,
+}
+
+/// The in-memory database behind the datasources created with
+/// [`crate::datasources::DatasourceConfig::mock`]. Its clones share the same
+/// statements and scripted results, so the test keeps one to drive the datasource
+#[derive(Clone)]
+pub struct MockDatabase {
+    dialect: DatabaseType,
+    state: Arc<Mutex<MockState>>,
+}
+
+impl MockDatabase {
+    /// Creates a database that emulates the SQL dialect of the given database
+    pub fn new(dialect: DatabaseType) -> Self {
+        Self {
+            dialect,
+            state: Arc::new(Mutex::new(MockState::default())),
+        }
+    }
+
+    /// The database whose SQL dialect is emulated
+    pub fn dialect(&self) -> DatabaseType {
+        self.dialect
+    }
+
+    /// Scripts the rows returned by the next statement that isn't answered yet.
+    /// The statements without a scripted result return no rows
+    pub fn push_rows(&self, rows: impl IntoIterator<Item = MockRow>) {
+        self.lock()
+            .results
+            .push_back(Ok(rows.into_iter().collect()));
+    }
+
+    /// Scripts an error for the next statement that isn't answered yet
+    pub fn push_error(&self, message: impl Into<String>) {
+        self.lock()
+            .results
+            .push_back(Err(MockError(message.into())));
+    }
+
+    /// The statements received so far, in order
+    pub fn statements(&self) -> Vec<MockStatement> {
+        self.lock().statements.clone()
+    }
+
+    /// Forgets the received statements and the results not returned yet
+    pub fn clear(&self) {
+        let mut state = self.lock();
+        state.statements.clear();
+        state.results.clear();
+    }
+
+    /// Records the statement, and returns the next scripted result
+    pub fn execute(
+        &self,
+        sql: impl Into<String>,
+        params: Vec<MockValue>,
+    ) -> Result<Vec<MockRow>, MockError> {
+        let mut state = self.lock();
+        state.statements.push(MockStatement {
+            sql: sql.into(),
+            params,
+        });
+        state.results.pop_front().unwrap_or_else(|| Ok(Vec::new()))
+    }
+
+    fn lock(&self) -> MutexGuard<'_, MockState> {
+        self.state
+            .lock()
+            .expect("The state of the mock database is poisoned")
+    }
+}
+
+impl fmt::Debug for MockDatabase {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("MockDatabase")
+            .field("dialect", &self.dialect)
+            .finish_non_exhaustive()
+    }
+}
+
+/// Two mocks are the same database when they share their state
+impl PartialEq for MockDatabase {
+    fn eq(&self, other: &Self) -> bool {
+        Arc::ptr_eq(&self.state, &other.state)
+    }
+}
+
+#[cfg(test)]
+mod mock_tests {
+    use super::*;
+
+    #[test]
+    fn get_the_values_of_a_mock_row() {
+        let row = MockRow::new([
+            ("id", MockValue::from(1)),
+            ("name", "LCK".into()),
+            ("rating", 2.5f32.into()),
+            ("parent", MockValue::from(None::<i32>)),
+        ]);
+
+        assert_eq!(row.columns(), ["id", "name", "rating", "parent"]);
+        assert_eq!(row.try_get::<i32>("id"), Ok(1));
+        assert_eq!(row.try_get::<i64>("id"), Ok(1));
+        assert_eq!(row.try_get::<String>("name"), Ok("LCK".to_string()));
+        assert_eq!(row.try_get::<f64>("rating"), Ok(2.5));
+        assert_eq!(row.try_get::<Option<i32>>("parent"), Ok(None));
+        assert_eq!(
+            row.try_get::<Option<String>>("name"),
+            Ok(Some("LCK".into()))
+        );
+
+        assert!(row.try_get::<i32>("name").is_err());
+        assert!(row.try_get::<i32>("parent").is_err());
+        assert!(row.try_get::<i32>("unknown").is_err());
+        assert!(row.try_get_at::<i32>(4).is_err());
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn record_the_statements_of_a_mock_database() {
+        let database = MockDatabase::new(DatabaseType::PostgreSql);
+        let shared = database.clone();
+        assert_eq!(database, shared);
+
+        database.push_rows([MockRow::new([("id", MockValue::from(1))])]);
+        database.push_error("connection reset");
+
+        let rows = shared
+            .execute("SELECT * FROM league WHERE id = $1", vec![1.into()])
+            .unwrap();
+        assert_eq!(rows.len(), 1);
+        assert_eq!(
+            shared.execute("DELETE FROM league", Vec::new()),
+            Err(MockError("connection reset".to_string()))
+        );
+        assert_eq!(shared.execute("SELECT 1", Vec::new()), Ok(Vec::new()));
+
+        let statements = database.statements();
+        assert_eq!(statements.len(), 3);
+        assert_eq!(statements[0].sql, "SELECT * FROM league WHERE id = $1");
+        assert_eq!(statements[0].params, [MockValue::Int(1)]);
+
+        database.clear();
+        assert!(database.statements().is_empty());
+    }
+}
@@ -0,0 +1,565 @@
+use std::collections::VecDeque;
+use std::fmt;
+use std::ops::{Deref, DerefMut};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use serde::Deserialize;
+use tokio::sync::{OwnedSemaphorePermit, Semaphore};
+
+use crate::canyon_database_connector::DatabaseConnection;
+use crate::datasources::DatasourceConfig;
+
+/// The sizing and the lifetime rules of the connection pool of a datasource.
+///
+/// They are read from the `properties.pool` table of every datasource, and
+/// any missing key falls back to its default value:
+///
+/// ```toml
+/// [canyon_sql.datasources.properties.pool]
+/// min_size = 1            # connections opened eagerly and never evicted for being idle
+/// max_size = 10           # upper bound of live connections at the same time
+/// acquire_timeout = 30    # seconds waiting for a free connection before giving up
+/// idle_timeout = 600      # seconds an idle connection is kept alive. 0 keeps them forever
+/// test_on_checkout = true # pings the idle connections before handing them out again
+/// ```
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct PoolProperties {
+    pub min_size: usize,
+    pub max_size: usize,
+    pub acquire_timeout: u64,
+    pub idle_timeout: u64,
+    pub test_on_checkout: bool,
+    pub reconnect: ReconnectPolicy,
+}
+
+impl Default for PoolProperties {
+    fn default() -> Self {
+        Self {
+            min_size: 1,
+            max_size: 10,
+            acquire_timeout: 30,
+            idle_timeout: 600,
+            test_on_checkout: true,
+            reconnect: ReconnectPolicy::default(),
+        }
+    }
+}
+
+/// How many times, and how spaced, a pool retries to open a new connection when the
+/// database can't be reached, so the pool recovers on its own from a restart or a
+/// failover of the database. Only the network errors are retried, since a rejected
+/// login or a wrong configuration fails again on every attempt. Read from the
+/// `properties.pool.reconnect` table:
+///
+/// ```toml
+/// [canyon_sql.datasources.properties.pool.reconnect]
+/// max_retries = 3         # retries after a failed attempt. 0 fails on the first error
+/// initial_backoff = 200   # milliseconds waited before the first retry
+/// max_backoff = 5000      # upper bound of the wait, which doubles after every retry
+/// ```
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct ReconnectPolicy {
+    pub max_retries: u32,
+    pub initial_backoff: u64,
+    pub max_backoff: u64,
+}
+
+impl Default for ReconnectPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            initial_backoff: 200,
+            max_backoff: 5000,
+        }
+    }
+}
+
+impl ReconnectPolicy {
+    /// The time waited before the given retry, counting from 1
+    pub fn backoff(&self, retry: u32) -> Duration {
+        let factor = 2_u64.saturating_pow(retry.saturating_sub(1));
+        Duration::from_millis(
+            self.initial_backoff
+                .saturating_mul(factor)
+                .min(self.max_backoff),
+        )
+    }
+}
+
+impl PoolProperties {
+    /// The maximum number of connections of the pool, which will be always at least one
+    pub fn max_size(&self) -> usize {
+        self.max_size.max(1)
+    }
+
+    /// The number of connections that the pool will keep open, which can't be
+    /// greater than [`PoolProperties::max_size`]
+    pub fn min_size(&self) -> usize {
+        self.min_size.min(self.max_size())
+    }
+
+    pub fn acquire_timeout(&self) -> Duration {
+        Duration::from_secs(self.acquire_timeout)
+    }
+
+    pub fn idle_timeout(&self) -> Option<Duration> {
+        (self.idle_timeout > 0).then(|| Duration::from_secs(self.idle_timeout))
+    }
+}
+
+/// The errors that may happen while checking out a connection from a pool
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PoolError {
+    /// No connection was released before the `acquire_timeout` of the datasource expired
+    Timeout {
+        datasource: String,
+        waited: Duration,
+    },
+    /// There's no pool registered for the requested datasource
+    DatasourceNotFound(String),
+    /// The datasource failed to connect recently, so it isn't tried again for a while
+    Unhealthy(String),
+}
+
+impl fmt::Display for PoolError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            PoolError::Timeout { datasource, waited } => write!(
+                f,
+                "Timed out after {waited:?} waiting for a free connection of the datasource: {datasource}"
+            ),
+            PoolError::DatasourceNotFound(datasource) => write!(
+                f,
+                "Canyon couldn't find a connection pool for the datasource: {datasource}"
+            ),
+            PoolError::Unhealthy(datasource) => write!(
+                f,
+                "The datasource failed to connect recently, so it's skipped for now: {datasource}"
+            ),
+        }
+    }
+}
+
+impl std::error::Error for PoolError {}
+
+/// The datasources whose pools couldn't open their initial connections, with the error
+/// of each one. Their pools are registered anyway, and they'll connect when used
+#[derive(Debug)]
+pub struct InitConnectionsError {
+    pub failures: Vec<(String, Box<dyn std::error::Error + Send + Sync + 'static>)>,
+}
+
+impl fmt::Display for InitConnectionsError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Couldn't connect to some datasources:")?;
+        for (datasource, error) in &self.failures {
+            write!(f, "\n - {datasource}: {error}")?;
+        }
+        Ok(())
+    }
+}
+
+impl std::error::Error for InitConnectionsError {}
+
+/// A connection waiting in the pool to be checked out again
+struct IdleConnection {
+    conn: DatabaseConnection,
+    since: Instant,
+}
+
+/// A pool of [`DatabaseConnection`] against one datasource.
+///
+/// Every checkout takes one permit of a semaphore sized with the `max_size` of the
+/// datasource, so there are never more live connections than that. Then, an idle
+/// connection is reused if there's one, or a new one is opened otherwise. Connections
+/// idle for longer than the `idle_timeout` are closed while the pool is used, always
+/// keeping at least `min_size` of them.
+pub struct DatabaseConnectionPool {
+    datasource: DatasourceConfig,
+    properties: PoolProperties,
+    idle: Mutex<VecDeque<IdleConnection>>,
+    permits: Arc<Semaphore>,
+    next_replica: AtomicUsize,
+    unhealthy_until: Mutex<Option<Instant>>,
+}
+
+impl DatabaseConnectionPool {
+    /// Creates a new empty pool for the given datasource. No connection is opened
+    /// until [`DatabaseConnectionPool::warm_up`] or [`DatabaseConnectionPool::acquire`] are called
+    pub fn new(datasource: DatasourceConfig) -> Arc<Self> {
+        let properties = datasource.properties.pool;
+
+        Arc::new(Self {
+            datasource,
+            properties,
+            idle: Mutex::new(VecDeque::with_capacity(properties.max_size())),
+            permits: Arc::new(Semaphore::new(properties.max_size())),
+            next_replica: AtomicUsize::new(0),
+            unhealthy_until: Mutex::new(None),
+        })
+    }
+
+    /// Opens the `min_size` connections of the pool upfront
+    pub async fn warm_up(&self) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        while self.idle_connections() < self.properties.min_size() {
+            let conn = self.connect(self.properties.reconnect.max_retries).await?;
+            self.lock_idle().push_back(IdleConnection {
+                conn,
+                since: Instant::now(),
+            });
+        }
+        Ok(())
+    }
+
+    /// Checks out a connection from the pool, waiting for one to be released
+    /// when all of them are in use, up to the `acquire_timeout` of the datasource.
+    ///
+    /// The connection goes back to the pool when the returned [`PooledConnection`] is dropped
+    pub async fn acquire(
+        self: &Arc<Self>,
+    ) -> Result<PooledConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        self.checkout(self.properties.reconnect.max_retries).await
+    }
+
+    /// Checks out a connection like [`DatabaseConnectionPool::acquire`], but opening it
+    /// without retries, for the callers that can go elsewhere, like the reads sent to
+    /// the replicas. A pool that fails to connect is marked as unhealthy for the
+    /// `max_backoff` of its [`ReconnectPolicy`], and it fails at once meanwhile with a
+    /// [`PoolError::Unhealthy`]
+    pub async fn try_acquire(
+        self: &Arc<Self>,
+    ) -> Result<PooledConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mut unhealthy_until = *self.lock_unhealthy_until();
+        if unhealthy_until.is_some_and(|until| Instant::now() < until) {
+            return Err(PoolError::Unhealthy(self.datasource.name.clone()).into());
+        }
+
+        let result = self.checkout(0).await;
+        match &result {
+            Ok(_) => unhealthy_until = None,
+            // A pool whose connections are all in use is still healthy
+            Err(error) if error.downcast_ref::<PoolError>().is_some() => {}
+            Err(_) => {
+                unhealthy_until = Some(
+                    Instant::now() + Duration::from_millis(self.properties.reconnect.max_backoff),
+                )
+            }
+        }
+        *self.lock_unhealthy_until() = unhealthy_until;
+        result
+    }
+
+    /// Checks out a connection, retrying to open it up to the given times
+    async fn checkout(
+        self: &Arc<Self>,
+        max_retries: u32,
+    ) -> Result<PooledConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let waited = self.properties.acquire_timeout();
+        let permit = tokio::time::timeout(waited, self.permits.clone().acquire_owned())
+            .await
+            .map_err(|_| PoolError::Timeout {
+                datasource: self.datasource.name.clone(),
+                waited,
+            })?
+            .expect("The semaphore of a connection pool is never closed");
+
+        // The idle connections that the database closed meanwhile are discarded
+        let conn = loop {
+            let reused = {
+                let mut idle = self.lock_idle();
+                self.evict_expired(&mut idle);
+                idle.pop_back()
+            };
+
+            match reused {
+                Some(mut idle) => {
+                    if self.is_alive(&mut idle.conn).await {
+                        break idle.conn;
+                    }
+                }
+                None => break self.connect(max_retries).await?,
+            }
+        };
+
+        Ok(PooledConnection {
+            conn: Some(conn),
+            pool: self.clone(),
+            invalidated: false,
+            _permit: permit,
+        })
+    }
+
+    /// Checks out a connection and makes a round trip to the database with it
+    pub async fn ping(
+        self: &Arc<Self>,
+    ) -> Result<(), Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let mut conn = self.acquire().await?;
+        conn.ping().await
+    }
+
+    /// The configuration of the datasource that this pool connects to
+    pub fn datasource(&self) -> &DatasourceConfig {
+        &self.datasource
+    }
+
+    /// The `replicas` of the datasource in the order to try them for the next read,
+    /// starting by a different one every time so the reads are balanced between them
+    pub fn replicas_round_robin(&self) -> impl Iterator<Item = &str> {
+        let replicas = &self.datasource.replicas;
+        let first = match replicas.len() {
+            0 => 0,
+            len => self.next_replica.fetch_add(1, Ordering::Relaxed) % len,
+        };
+        replicas[first..]
+            .iter()
+            .chain(&replicas[..first])
+            .map(String::as_str)
+    }
+
+    /// The number of open connections that are currently waiting to be checked out
+    pub fn idle_connections(&self) -> usize {
+        self.lock_idle().len()
+    }
+
+    /// The number of connections currently checked out of the pool
+    pub fn connections_in_use(&self) -> usize {
+        self.properties.max_size() - self.permits.available_permits()
+    }
+
+    /// Opens a new connection, retrying up to the given times with the backoff of the
+    /// [`ReconnectPolicy`] of the pool while the database can't be reached. Any other
+    /// error is returned at once
+    async fn connect(
+        &self,
+        max_retries: u32,
+    ) -> Result<DatabaseConnection, Box<dyn std::error::Error + Send + Sync + 'static>> {
+        let policy = self.properties.reconnect;
+        let mut retry = 0;
+        loop {
+            match DatabaseConnection::new(&self.datasource).await {
+                Ok(conn) => return Ok(conn),
+                Err(error) if retry < max_retries && is_transient(error.as_ref()) => {
+                    retry += 1;
+                    tokio::time::sleep(policy.backoff(retry)).await;
+                }
+                Err(error) => return Err(error),
+            }
+        }
+    }
+
+    async fn is_alive(&self, conn: &mut DatabaseConnection) -> bool {
+        !conn.is_closed() && (!self.properties.test_on_checkout || conn.ping().await.is_ok())
+    }
+
+    fn release(&self, conn: DatabaseConnection) {
+        if conn.is_closed() {
+            return;
+        }
+        let mut idle = self.lock_idle();
+        idle.push_back(IdleConnection {
+            conn,
+            since: Instant::now(),
+        });
+        self.evict_expired(&mut idle);
+    }
+
+    /// Closes the oldest connections that have been idle for longer than the `idle_timeout`,
+    /// without going below the `min_size` of the pool
+    fn evict_expired(&self, idle: &mut VecDeque<IdleConnection>) {
+        if let Some(idle_timeout) = self.properties.idle_timeout() {
+            while idle.len() > self.properties.min_size()
+                && idle
+                    .front()
+                    .is_some_and(|oldest| oldest.since.elapsed() >= idle_timeout)
+            {
+                idle.pop_front();
+            }
+        }
+    }
+
+    fn lock_unhealthy_until(&self) -> std::sync::MutexGuard<'_, Option<Instant>> {
+        // A plain value can't be left inconsistent, so a poisoned lock is still usable
+        self.unhealthy_until
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+
+    fn lock_idle(&self) -> std::sync::MutexGuard<'_, VecDeque<IdleConnection>> {
+        // The guarded queue is never left in an inconsistent state, so a poisoned lock is still usable
+        self.idle
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+    }
+}
+
+/// A [`DatabaseConnection`] checked out from a [`DatabaseConnectionPool`].
+///
+/// Dereferences to the underlying connection, and gives it back to its pool when dropped
+pub struct PooledConnection {
+    conn: Option<DatabaseConnection>,
+    pool: Arc<DatabaseConnectionPool>,
+    invalidated: bool,
+    _permit: OwnedSemaphorePermit,
+}
+
+impl PooledConnection {
+    /// The configuration of the datasource where this connection belongs
+    pub fn datasource(&self) -> &DatasourceConfig {
+        &self.pool.datasource
+    }
+
+    /// Closes the connection instead of giving it back to its pool, for the cases
+    /// where it's left in a state that can't be reused, like an unfinished transaction
+    pub fn discard(mut self) {
+        self.conn.take();
+    }
+
+    /// Closes the connection when it's dropped instead of giving it back to its pool, for
+    /// the connections left in an unknown state while they're still in use, like the ones
+    /// whose statement was cancelled
+    pub fn invalidate(&mut self) {
+        self.invalidated = true;
+    }
+
+    /// Whether the connection will be closed instead of given back to its pool
+    pub fn is_invalidated(&self) -> bool {
+        self.invalidated
+    }
+}
+
+impl Deref for PooledConnection {
+    type Target = DatabaseConnection;
+
+    fn deref(&self) -> &Self::Target {
+        self.conn
+            .as_ref()
+            .expect("A pooled connection is only taken when it's dropped")
+    }
+}
+
+impl DerefMut for PooledConnection {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        self.conn
+            .as_mut()
+            .expect("A pooled connection is only taken when it's dropped")
+    }
+}
+
+impl Drop for PooledConnection {
+    fn drop(&mut self) {
+        if let Some(conn) = self.conn.take() {
+            if !self.invalidated {
+                self.pool.release(conn);
+            }
+        }
+    }
+}
+
+/// Whether the error, or any of its sources, is a network error that may not happen
+/// again, like a refused or reset connection while the database restarts
+fn is_transient(error: &(dyn std::error::Error + 'static)) -> bool {
+    use std::io::ErrorKind;
+
+    std::iter::successors(Some(error), |error| error.source()).any(|error| {
+        matches!(
+            io_error_kind(error),
+            Some(
+                ErrorKind::ConnectionRefused
+                    | ErrorKind::ConnectionReset
+                    | ErrorKind::ConnectionAborted
+                    | ErrorKind::NotConnected
+                    | ErrorKind::BrokenPipe
+                    | ErrorKind::TimedOut
+                    | ErrorKind::Interrupted
+                    | ErrorKind::UnexpectedEof
+            )
+        )
+    })
+}
+
+/// The kind of the error when it comes from the I/O of the connection
+fn io_error_kind(error: &(dyn std::error::Error + 'static)) -> Option<std::io::ErrorKind> {
+    if let Some(error) = error.downcast_ref::<std::io::Error>() {
+        return Some(error.kind());
+    }
+    // The driver of SQL Server keeps only the kind of its I/O errors
+    #[cfg(feature = "mssql")]
+    if let Some(tiberius::error::Error::Io { kind, .. }) =
+        error.downcast_ref::<tiberius::error::Error>()
+    {
+        return Some(*kind);
+    }
+    None
+}
+
+#[cfg(test)]
+mod pool_properties {
+    use super::*;
+
+    #[test]
+    fn missing_pool_keys_take_the_defaults() {
+        let properties: PoolProperties = toml::from_str("max_size = 4").unwrap();
+
+        assert_eq!(properties.max_size(), 4);
+        assert_eq!(properties.min_size(), 1);
+        assert_eq!(properties.acquire_timeout(), Duration::from_secs(30));
+        assert_eq!(properties.idle_timeout(), Some(Duration::from_secs(600)));
+        assert!(properties.test_on_checkout);
+        assert_eq!(properties.reconnect, ReconnectPolicy::default());
+    }
+
+    #[test]
+    fn reconnect_backoff_doubles_up_to_its_maximum() {
+        let properties: PoolProperties =
+            toml::from_str("reconnect = { initial_backoff = 100, max_backoff = 500 }").unwrap();
+        let policy = properties.reconnect;
+
+        assert_eq!(policy.max_retries, 3);
+        assert_eq!(policy.backoff(1), Duration::from_millis(100));
+        assert_eq!(policy.backoff(2), Duration::from_millis(200));
+        assert_eq!(policy.backoff(3), Duration::from_millis(400));
+        assert_eq!(policy.backoff(4), Duration::from_millis(500));
+        assert_eq!(policy.backoff(u32::MAX), Duration::from_millis(500));
+    }
+
+    #[test]
+    fn pool_sizes_are_kept_consistent() {
+        let properties: PoolProperties =
+            toml::from_str("min_size = 8\nmax_size = 0\nidle_timeout = 0").unwrap();
+
+        assert_eq!(properties.max_size(), 1);
+        assert_eq!(properties.min_size(), 1);
+        assert_eq!(properties.idle_timeout(), None);
+    }
+
+    #[test]
+    fn only_the_network_errors_are_retried() {
+        let refused = std::io::Error::from(std::io::ErrorKind::ConnectionRefused);
+        assert!(is_transient(&refused));
+
+        // A network error wrapped by the driver is found among its sources
+        #[derive(Debug)]
+        struct DriverError(std::io::Error);
+        impl fmt::Display for DriverError {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                write!(f, "Couldn't connect: {}", self.0)
+            }
+        }
+        impl std::error::Error for DriverError {
+            fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+                Some(&self.0)
+            }
+        }
+        assert!(is_transient(&DriverError(refused)));
+
+        let denied = std::io::Error::from(std::io::ErrorKind::PermissionDenied);
+        assert!(!is_transient(&denied));
+        let auth: Box<dyn std::error::Error + Send + Sync> =
+            "password authentication failed".into();
+        assert!(!is_transient(auth.as_ref()));
+    }
+}
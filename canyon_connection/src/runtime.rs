@@ -0,0 +1,126 @@
+//! The Tokio runtime that drives the programs of `#[canyon_sql::main]` and the tests of
+//! `#[canyon_sql::macros::canyon_tokio_test]`.
+//!
+//! It's only built the first time that it's needed, so the applications that run Canyon
+//! within their own runtime, calling [`crate::init`] or [`crate::init_connections_cache`]
+//! from it, never create a second one.
+use std::io;
+
+use serde::Deserialize;
+use tokio::runtime::{Builder, Runtime};
+
+/// The kind of scheduler of the runtime
+#[derive(Deserialize, Debug, Default, Clone, Copy, PartialEq, Eq)]
+pub enum RuntimeFlavor {
+    /// A pool of worker threads that run the tasks in parallel
+    #[default]
+    #[serde(alias = "multi_thread")]
+    MultiThread,
+    /// Every task runs on the thread that blocks on the runtime
+    #[serde(alias = "current_thread")]
+    CurrentThread,
+}
+
+/// The settings of the Tokio runtime of Canyon. They are read from the `runtime` table of
+/// the configuration, or given to [`crate::datasources::CanyonSqlConfigBuilder::runtime`],
+/// and any missing key falls back to its default value:
+///
+/// ```toml
+/// [canyon_sql.runtime]
+/// flavor = 'multi_thread'         # or 'current_thread'
+/// worker_threads = 4              # defaults to the number of CPU cores
+/// thread_name = 'canyon-worker'   # the name of the threads of the runtime
+/// ```
+#[derive(Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(default, deny_unknown_fields)]
+pub struct RuntimeProperties {
+    pub flavor: RuntimeFlavor,
+    pub worker_threads: Option<usize>,
+    pub thread_name: String,
+}
+
+impl Default for RuntimeProperties {
+    fn default() -> Self {
+        Self {
+            flavor: RuntimeFlavor::default(),
+            worker_threads: None,
+            thread_name: String::from("canyon-worker"),
+        }
+    }
+}
+
+impl RuntimeProperties {
+    /// Describes why the settings can't build a runtime, if they can't
+    pub fn validate(&self) -> Result<(), String> {
+        match (self.flavor, self.worker_threads) {
+            (_, Some(0)) => Err(String::from("the `worker_threads` must be greater than 0")),
+            (RuntimeFlavor::CurrentThread, Some(_)) => Err(String::from(
+                "the `worker_threads` can't be set for a `current_thread` runtime",
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Builds a new runtime with these settings, with the IO and time drivers enabled
+    pub fn build(&self) -> io::Result<Runtime> {
+        let mut builder = match self.flavor {
+            RuntimeFlavor::MultiThread => Builder::new_multi_thread(),
+            RuntimeFlavor::CurrentThread => Builder::new_current_thread(),
+        };
+        if let Some(worker_threads) = self.worker_threads {
+            builder.worker_threads(worker_threads);
+        }
+
+        builder.enable_all().thread_name(&self.thread_name).build()
+    }
+}
+
+#[cfg(test)]
+mod runtime_tests {
+    use super::*;
+
+    #[test]
+    fn build_the_configured_runtime() {
+        let properties: RuntimeProperties =
+            toml::from_str("flavor = 'current_thread'\nthread_name = 'canyon-test'").unwrap();
+        assert_eq!(properties.flavor, RuntimeFlavor::CurrentThread);
+        assert_eq!(properties.validate(), Ok(()));
+
+        let runtime = properties.build().expect("A valid runtime");
+        let thread_name =
+            runtime.block_on(async { std::thread::current().name().map(String::from) });
+        // A current thread runtime runs the tasks on the thread that blocks on it
+        assert_eq!(thread_name, std::thread::current().name().map(String::from));
+
+        let properties = RuntimeProperties {
+            worker_threads: Some(2),
+            ..Default::default()
+        };
+        let runtime = properties.build().expect("A valid runtime");
+        let thread_name = runtime.block_on(async {
+            tokio::spawn(async { std::thread::current().name().map(String::from) })
+                .await
+                .unwrap()
+        });
+        assert_eq!(thread_name.as_deref(), Some("canyon-worker"));
+        assert_eq!(runtime.metrics().num_workers(), 2);
+    }
+
+    #[test]
+    fn validate_the_runtime_properties() {
+        let zero_workers = RuntimeProperties {
+            worker_threads: Some(0),
+            ..Default::default()
+        };
+        assert!(zero_workers.validate().is_err());
+
+        let current_thread_workers = RuntimeProperties {
+            flavor: RuntimeFlavor::CurrentThread,
+            worker_threads: Some(4),
+            ..Default::default()
+        };
+        assert!(current_thread_workers.validate().is_err());
+
+        assert!(toml::from_str::<RuntimeProperties>("workers = 4").is_err());
+    }
+}
@@ -0,0 +1,258 @@
+//! The settings of the database session applied to every new connection of a datasource,
+//! so all the connections of its pool behave the same way.
+//!
+//! They're given by the `properties.session` table of the datasource, whose keys are
+//! `application_name`, `search_path` (or `schema`), `statement_timeout`, `time_zone` and
+//! `isolation_level`:
+//!
+//! ```toml
+//! [canyon_sql]
+//! datasources = [
+//!     { name = 'postgres_docker', url = '${DATABASE_URL}', properties.session.search_path = 'canyon, public', properties.session.statement_timeout = 5000 },
+//! ]
+//! ```
+use std::fmt;
+
+use serde::Deserialize;
+
+use crate::canyon_database_connector::DatabaseType;
+
+/// The isolation levels of the transactions, as defined by the SQL standard
+#[derive(Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IsolationLevel {
+    #[serde(alias = "read_uncommitted")]
+    ReadUncommitted,
+    #[serde(alias = "read_committed")]
+    ReadCommitted,
+    #[serde(alias = "repeatable_read")]
+    RepeatableRead,
+    #[serde(alias = "serializable")]
+    Serializable,
+}
+
+impl fmt::Display for IsolationLevel {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            IsolationLevel::ReadUncommitted => "READ UNCOMMITTED",
+            IsolationLevel::ReadCommitted => "READ COMMITTED",
+            IsolationLevel::RepeatableRead => "REPEATABLE READ",
+            IsolationLevel::Serializable => "SERIALIZABLE",
+        })
+    }
+}
+
+/// The session settings of the connections of a datasource. The ones that aren't given
+/// keep the defaults of the database, and the ones that the database of the datasource
+/// doesn't support are rejected when the configuration is validated
+#[derive(Deserialize, Debug, Clone, Default, PartialEq, Eq)]
+#[serde(deny_unknown_fields)]
+pub struct SessionProperties {
+    /// The name that identifies the application in the sessions listed by the database.
+    /// Not supported for MySQL
+    pub application_name: Option<String>,
+    /// The schemas where the unqualified tables are looked for, separated by commas.
+    /// MySQL only takes a single one, its default database. Not supported for SQL Server
+    #[serde(alias = "schema")]
+    pub search_path: Option<String>,
+    /// The time in milliseconds that a statement can run before being cancelled. SQL Server
+    /// only limits the time that a statement waits for a lock, and MySQL only limits the selects
+    pub statement_timeout: Option<u64>,
+    /// The time zone of the dates and times of the session, like `UTC` or `Europe/Madrid`.
+    /// Not supported for SQL Server
+    pub time_zone: Option<String>,
+    /// The isolation level of the transactions of the session
+    pub isolation_level: Option<IsolationLevel>,
+}
+
+impl SessionProperties {
+    /// The statements that apply the settings to a new connection of the given database.
+    ///
+    /// The `application_name` of SQL Server isn't set by a statement, but sent with the login
+    /// of the connection
+    pub fn statements(&self, db_type: DatabaseType) -> Vec<String> {
+        #[allow(unused_mut)]
+        let mut statements = Vec::new();
+        match db_type {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => {
+                if let Some(name) = &self.application_name {
+                    statements.push(format!("SET application_name = {}", quote_literal(name)));
+                }
+                if let Some(search_path) = &self.search_path {
+                    let schemas = search_path
+                        .split(',')
+                        .map(|schema| format!("\"{}\"", schema.trim().replace('"', "\"\"")))
+                        .collect::<Vec<_>>();
+                    statements.push(format!("SET search_path TO {}", schemas.join(", ")));
+                }
+                if let Some(timeout) = self.statement_timeout {
+                    statements.push(format!("SET statement_timeout = {timeout}"));
+                }
+                if let Some(time_zone) = &self.time_zone {
+                    statements.push(format!("SET TIME ZONE {}", quote_literal(time_zone)));
+                }
+                if let Some(level) = self.isolation_level {
+                    statements.push(format!(
+                        "SET SESSION CHARACTERISTICS AS TRANSACTION ISOLATION LEVEL {level}"
+                    ));
+                }
+            }
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => {
+                if let Some(timeout) = self.statement_timeout {
+                    statements.push(format!("SET LOCK_TIMEOUT {timeout}"));
+                }
+                if let Some(level) = self.isolation_level {
+                    statements.push(format!("SET TRANSACTION ISOLATION LEVEL {level}"));
+                }
+            }
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => {
+                if let Some(schema) = &self.search_path {
+                    statements.push(format!("USE `{}`", schema.trim().replace('`', "``")));
+                }
+                if let Some(timeout) = self.statement_timeout {
+                    statements.push(format!("SET SESSION max_execution_time = {timeout}"));
+                }
+                if let Some(time_zone) = &self.time_zone {
+                    statements.push(format!(
+                        "SET time_zone = {}",
+                        quote_literal(&time_zone.replace('\\', "\\\\"))
+                    ));
+                }
+                if let Some(level) = self.isolation_level {
+                    statements.push(format!("SET SESSION TRANSACTION ISOLATION LEVEL {level}"));
+                }
+            }
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => {}
+        }
+        statements
+    }
+
+    /// The settings given that the database doesn't support, named as their properties
+    pub fn unsupported(&self, db_type: DatabaseType) -> Vec<&'static str> {
+        // Whether the database supports the application name, the search path, the statement
+        // timeout, the time zone and the isolation level, in this order
+        let supported = match db_type {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => [true; 5],
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => [true, false, true, false, true],
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => [false, true, true, true, true],
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => [false; 5],
+        };
+        let given = [
+            ("session.application_name", self.application_name.is_some()),
+            ("session.search_path", self.search_path.is_some()),
+            (
+                "session.statement_timeout",
+                self.statement_timeout.is_some(),
+            ),
+            ("session.time_zone", self.time_zone.is_some()),
+            ("session.isolation_level", self.isolation_level.is_some()),
+        ];
+
+        given
+            .into_iter()
+            .zip(supported)
+            .filter(|((_, is_given), is_supported)| *is_given && !is_supported)
+            .map(|((property, _), _)| property)
+            .collect()
+    }
+}
+
+/// Writes the value as a SQL string literal
+#[cfg(any(feature = "postgres", feature = "mysql"))]
+fn quote_literal(value: &str) -> String {
+    format!("'{}'", value.replace('\'', "''"))
+}
+
+#[cfg(test)]
+mod session_tests {
+    use super::*;
+
+    fn session() -> SessionProperties {
+        toml::from_str(
+            r#"
+            application_name = "triforce"
+            schema = "canyon, public"
+            statement_timeout = 5000
+            time_zone = "Europe/Madrid"
+            isolation_level = "repeatable_read"
+            "#,
+        )
+        .expect("A valid session")
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_session_statements() {
+        assert_eq!(
+            session().statements(DatabaseType::PostgreSql),
+            [
+                "SET application_name = 'triforce'",
+                r#"SET search_path TO "canyon", "public""#,
+                "SET statement_timeout = 5000",
+                "SET TIME ZONE 'Europe/Madrid'",
+                "SET SESSION CHARACTERISTICS AS TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+            ]
+        );
+        assert!(session().unsupported(DatabaseType::PostgreSql).is_empty());
+
+        let quoted = SessionProperties {
+            application_name: Some("Link's app".to_string()),
+            ..Default::default()
+        };
+        assert_eq!(
+            quoted.statements(DatabaseType::PostgreSql),
+            ["SET application_name = 'Link''s app'"]
+        );
+    }
+
+    #[cfg(feature = "mssql")]
+    #[test]
+    fn sqlserver_session_statements() {
+        assert_eq!(
+            session().statements(DatabaseType::SqlServer),
+            [
+                "SET LOCK_TIMEOUT 5000",
+                "SET TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+            ]
+        );
+        assert_eq!(
+            session().unsupported(DatabaseType::SqlServer),
+            ["session.search_path", "session.time_zone"]
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn mysql_session_statements() {
+        let session = SessionProperties {
+            search_path: Some("triforce".to_string()),
+            ..session()
+        };
+        assert_eq!(
+            session.statements(DatabaseType::MySQL),
+            [
+                "USE `triforce`",
+                "SET SESSION max_execution_time = 5000",
+                "SET time_zone = 'Europe/Madrid'",
+                "SET SESSION TRANSACTION ISOLATION LEVEL REPEATABLE READ",
+            ]
+        );
+        assert_eq!(
+            session.unsupported(DatabaseType::MySQL),
+            ["session.application_name"]
+        );
+    }
+
+    #[test]
+    fn reject_unknown_session_settings() {
+        assert!(toml::from_str::<SessionProperties>("lock_timeout = 10").is_err());
+        assert!(toml::from_str::<SessionProperties>("isolation_level = 'snapshot'").is_err());
+    }
+}
@@ -0,0 +1,183 @@
+#[cfg(any(feature = "postgres-tls", feature = "mssql"))]
+use std::error::Error;
+#[cfg(feature = "postgres-tls")]
+use std::fs;
+
+#[cfg(feature = "postgres-tls")]
+use native_tls::{Certificate, Identity, TlsConnector};
+#[cfg(feature = "postgres-tls")]
+use postgres_native_tls::MakeTlsConnector;
+#[cfg(feature = "mssql")]
+use tiberius::{Config, EncryptionLevel};
+
+#[cfg(any(feature = "postgres-tls", feature = "mssql", test))]
+use crate::datasources::DatasourceProperties;
+#[cfg(feature = "mssql")]
+use crate::datasources::SqlServerEncryption;
+#[cfg(feature = "postgres")]
+use crate::datasources::SslMode;
+
+/// The `tokio-postgres` mode that negotiates TLS as the given [`SslMode`] does.
+///
+/// `tokio-postgres` only knows if TLS is mandatory or not. The verification of the
+/// certificates of the `verify-*` modes is done by the connector
+#[cfg(feature = "postgres")]
+pub(crate) fn postgres_ssl_mode(sslmode: SslMode) -> tokio_postgres::config::SslMode {
+    match sslmode {
+        SslMode::Disable => tokio_postgres::config::SslMode::Disable,
+        SslMode::Prefer => tokio_postgres::config::SslMode::Prefer,
+        SslMode::Require | SslMode::VerifyCa | SslMode::VerifyFull => {
+            tokio_postgres::config::SslMode::Require
+        }
+    }
+}
+
+/// Builds the TLS connector of a `PostgreSQL` datasource from the `ssl*` keys of its properties.
+/// It's only available with the `postgres-tls` feature, which needs OpenSSL on Linux.
+///
+/// As `libpq` does, the `prefer` and `require` modes only verify the certificate of the
+/// server when a `sslrootcert` is given, `verify-ca` always verifies that it's signed by
+/// a trusted authority and `verify-full` also checks that it belongs to the host
+#[cfg(feature = "postgres-tls")]
+pub(crate) fn postgres_tls_connector(
+    properties: &DatasourceProperties,
+) -> Result<MakeTlsConnector, Box<dyn Error + Send + Sync>> {
+    let mut builder = TlsConnector::builder();
+
+    if let Some(sslrootcert) = &properties.sslrootcert {
+        for certificate in Certificate::stack_from_pem(&read_pem_file(sslrootcert)?)? {
+            builder.add_root_certificate(certificate);
+        }
+    }
+
+    match (&properties.sslcert, &properties.sslkey) {
+        (Some(sslcert), Some(sslkey)) => {
+            builder.identity(Identity::from_pkcs8(
+                &read_pem_file(sslcert)?,
+                &read_pem_file(sslkey)?,
+            )?);
+        }
+        (None, None) => {}
+        _ => return Err("The `sslcert` and `sslkey` properties must be given together".into()),
+    }
+
+    match properties.sslmode {
+        SslMode::Disable | SslMode::Prefer | SslMode::Require => {
+            if properties.sslrootcert.is_none() {
+                builder.danger_accept_invalid_certs(true);
+            }
+            builder.danger_accept_invalid_hostnames(true);
+        }
+        SslMode::VerifyCa => {
+            builder.danger_accept_invalid_hostnames(true);
+        }
+        SslMode::VerifyFull => {}
+    }
+
+    Ok(MakeTlsConnector::new(builder.build()?))
+}
+
+#[cfg(feature = "postgres-tls")]
+fn read_pem_file(path: &str) -> Result<Vec<u8>, Box<dyn Error + Send + Sync>> {
+    fs::read(path).map_err(|e| format!("Can't read the PEM file {path:?}: {e}").into())
+}
+
+/// Sets how the connections of a `SqlServer` datasource are encrypted, and how the
+/// certificate of the server is validated.
+///
+/// Unless `trust_cert_ca` or `trust_server_certificate` are given, the certificate
+/// is validated against the authorities trusted by the system
+#[cfg(feature = "mssql")]
+pub(crate) fn configure_sqlserver_tls(
+    config: &mut Config,
+    properties: &DatasourceProperties,
+) -> Result<(), Box<dyn Error + Send + Sync>> {
+    config.encryption(match properties.encryption {
+        SqlServerEncryption::Off => EncryptionLevel::Off,
+        SqlServerEncryption::On => EncryptionLevel::On,
+        SqlServerEncryption::Required => EncryptionLevel::Required,
+    });
+
+    match (
+        &properties.trust_cert_ca,
+        properties.trust_server_certificate,
+    ) {
+        (Some(_), true) => {
+            return Err(
+                "The `trust_cert_ca` and `trust_server_certificate` properties can't be combined"
+                    .into(),
+            )
+        }
+        (Some(trust_cert_ca), false) => config.trust_cert_ca(trust_cert_ca),
+        (None, true) => config.trust_cert(),
+        (None, false) => {}
+    }
+
+    Ok(())
+}
+
+#[cfg(test)]
+mod tls_tests {
+    use super::*;
+
+    fn properties(toml: &str) -> DatasourceProperties {
+        toml::from_str(toml).unwrap()
+    }
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn verification_modes_require_tls() {
+        assert_eq!(
+            postgres_ssl_mode(properties("sslmode = 'disable'").sslmode),
+            tokio_postgres::config::SslMode::Disable
+        );
+        assert_eq!(
+            postgres_ssl_mode(properties("sslmode = 'prefer'").sslmode),
+            tokio_postgres::config::SslMode::Prefer
+        );
+        for sslmode in ["require", "verify-ca", "verify-full"] {
+            assert_eq!(
+                postgres_ssl_mode(properties(&format!("sslmode = '{sslmode}'")).sslmode),
+                tokio_postgres::config::SslMode::Require
+            );
+        }
+    }
+
+    #[cfg(feature = "postgres-tls")]
+    #[test]
+    fn client_certificate_needs_its_key() {
+        let result =
+            postgres_tls_connector(&properties("sslmode = 'require'\nsslcert = 'client.crt'"));
+
+        assert!(result.is_err());
+    }
+
+    #[cfg(feature = "postgres-tls")]
+    #[test]
+    fn missing_root_certificate_is_reported() {
+        let result = postgres_tls_connector(&properties(
+            "sslmode = 'verify-full'\nsslrootcert = '/non/existent/root.crt'",
+        ));
+
+        let error = result.err().unwrap().to_string();
+        assert!(error.contains("/non/existent/root.crt"));
+    }
+
+    #[cfg(feature = "mssql")]
+    #[test]
+    fn sqlserver_trusted_authority_excludes_trusting_any_certificate() {
+        let mut config = Config::new();
+        let result = configure_sqlserver_tls(
+            &mut config,
+            &properties("trust_cert_ca = 'ca.crt'\ntrust_server_certificate = true"),
+        );
+        assert!(result.is_err());
+
+        let mut config = Config::new();
+        let result = configure_sqlserver_tls(
+            &mut config,
+            &properties("encryption = 'off'\ntrust_cert_ca = 'ca.crt'"),
+        );
+        assert!(result.is_ok());
+    }
+}
@@ -1,9 +1,15 @@
+#[cfg(feature = "sqlite")]
+use crate::rows::SqliteRow;
 use crate::{
     crud::{CrudOperations, Transaction},
     mapper::RowMapper,
 };
+#[cfg(feature = "mock")]
+use canyon_connection::mock::MockValue;
 #[cfg(feature = "mysql")]
 use canyon_connection::mysql_async::{self, prelude::ToValue};
+#[cfg(feature = "sqlite")]
+use canyon_connection::rusqlite;
 #[cfg(feature = "mssql")]
 use canyon_connection::tiberius::{self, ColumnData, IntoSql};
 #[cfg(feature = "postgres")]
@@ -108,6 +114,13 @@ impl Row for mysql_async::Row {
     }
 }
 
+#[cfg(feature = "sqlite")]
+impl Row for SqliteRow {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
+
 /// Generic abstraction for hold a Column type that will be one of the Column
 /// types present in the dependent crates
 // #[derive(Copy, Clone)]
@@ -151,6 +164,12 @@ impl Type for mysql_async::consts::ColumnType {
         self
     }
 }
+#[cfg(feature = "sqlite")]
+impl Type for rusqlite::types::Type {
+    fn as_any(&self) -> &dyn Any {
+        self
+    }
+}
 
 /// Wrapper over the dependencies Column's types
 pub enum ColumnType {
@@ -160,6 +179,10 @@ pub enum ColumnType {
     SqlServer(tiberius::ColumnType),
     #[cfg(feature = "mysql")]
     MySQL(mysql_async::consts::ColumnType),
+    /// SQLite has no fixed types for its columns, so it's the type of the value
+    /// stored in the column of a concrete row
+    #[cfg(feature = "sqlite")]
+    Sqlite(rusqlite::types::Type),
 }
 
 pub trait RowOperations {
@@ -175,6 +198,10 @@ pub trait RowOperations {
     fn get_mysql<'a, Output>(&'a self, col_name: &'a str) -> Output
     where
         Output: mysql_async::prelude::FromValue;
+    #[cfg(feature = "sqlite")]
+    fn get_sqlite<'a, Output>(&'a self, col_name: &'a str) -> Output
+    where
+        Output: rusqlite::types::FromSql;
 
     #[cfg(feature = "postgres")]
     fn get_postgres_opt<'a, Output>(&'a self, col_name: &'a str) -> Option<Output>
@@ -189,6 +216,10 @@ pub trait RowOperations {
     fn get_mysql_opt<'a, Output>(&'a self, col_name: &'a str) -> Option<Output>
     where
         Output: mysql_async::prelude::FromValue;
+    #[cfg(feature = "sqlite")]
+    fn get_sqlite_opt<'a, Output>(&'a self, col_name: &'a str) -> Option<Output>
+    where
+        Output: rusqlite::types::FromSql;
 
     fn columns(&self) -> Vec<Column>;
 }
@@ -226,6 +257,15 @@ impl RowOperations for &dyn Row {
             .expect("Failed to obtain a column in the MySql")
     }
 
+    #[cfg(feature = "sqlite")]
+    fn get_sqlite<'a, Output>(&'a self, col_name: &'a str) -> Output
+    where
+        Output: rusqlite::types::FromSql,
+    {
+        self.get_sqlite_opt(col_name)
+            .expect("Failed to obtain a column in the SQLite")
+    }
+
     #[cfg(feature = "postgres")]
     fn get_postgres_opt<'a, Output>(&'a self, col_name: &'a str) -> Option<Output>
     where
@@ -257,6 +297,16 @@ impl RowOperations for &dyn Row {
         };
         panic!() // TODO into result and propagate
     }
+    #[cfg(feature = "sqlite")]
+    fn get_sqlite_opt<'a, Output>(&'a self, col_name: &'a str) -> Option<Output>
+    where
+        Output: rusqlite::types::FromSql,
+    {
+        if let Some(row) = self.as_any().downcast_ref::<SqliteRow>() {
+            return row.try_get::<Option<Output>>(col_name).ok().flatten();
+        };
+        panic!() // TODO into result and propagate
+    }
 
     fn columns(&self) -> Vec<Column> {
         let mut cols = vec![];
@@ -304,6 +354,21 @@ impl RowOperations for &dyn Row {
                 })
             }
         }
+        #[cfg(feature = "sqlite")]
+        {
+            if let Some(sqlite_row) = self.as_any().downcast_ref::<SqliteRow>() {
+                sqlite_row
+                    .columns()
+                    .iter()
+                    .zip(sqlite_row.values())
+                    .for_each(|(name, value)| {
+                        cols.push(Column {
+                            name: Cow::from(name.as_str()),
+                            type_: ColumnType::Sqlite(value.data_type()),
+                        })
+                    })
+            }
+        }
 
         cols
     }
@@ -318,6 +383,10 @@ pub trait QueryParameter<'a>: std::fmt::Debug + Sync + Send {
     fn as_sqlserver_param(&self) -> ColumnData<'_>;
     #[cfg(feature = "mysql")]
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue;
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql;
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue;
 }
 
 /// The implementation of the [`canyon_connection::tiberius`] [`IntoSql`] for the
@@ -350,6 +419,14 @@ impl<'a> QueryParameter<'a> for bool {
     fn as_mysql_param(&self) -> &dyn ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for i16 {
     #[cfg(feature = "postgres")]
@@ -364,6 +441,14 @@ impl<'a> QueryParameter<'a> for i16 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &i16 {
     #[cfg(feature = "postgres")]
@@ -378,6 +463,14 @@ impl<'a> QueryParameter<'a> for &i16 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<i16> {
     #[cfg(feature = "postgres")]
@@ -392,6 +485,14 @@ impl<'a> QueryParameter<'a> for Option<i16> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&i16> {
     #[cfg(feature = "postgres")]
@@ -406,6 +507,14 @@ impl<'a> QueryParameter<'a> for Option<&i16> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.copied())
+    }
 }
 impl<'a> QueryParameter<'a> for i32 {
     #[cfg(feature = "postgres")]
@@ -420,6 +529,14 @@ impl<'a> QueryParameter<'a> for i32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &i32 {
     #[cfg(feature = "postgres")]
@@ -434,6 +551,14 @@ impl<'a> QueryParameter<'a> for &i32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<i32> {
     #[cfg(feature = "postgres")]
@@ -448,6 +573,14 @@ impl<'a> QueryParameter<'a> for Option<i32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&i32> {
     #[cfg(feature = "postgres")]
@@ -462,6 +595,14 @@ impl<'a> QueryParameter<'a> for Option<&i32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.copied())
+    }
 }
 impl<'a> QueryParameter<'a> for f32 {
     #[cfg(feature = "postgres")]
@@ -476,6 +617,14 @@ impl<'a> QueryParameter<'a> for f32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &f32 {
     #[cfg(feature = "postgres")]
@@ -490,6 +639,14 @@ impl<'a> QueryParameter<'a> for &f32 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<f32> {
     #[cfg(feature = "postgres")]
@@ -504,6 +661,14 @@ impl<'a> QueryParameter<'a> for Option<f32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&f32> {
     #[cfg(feature = "postgres")]
@@ -520,6 +685,14 @@ impl<'a> QueryParameter<'a> for Option<&f32> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.copied())
+    }
 }
 impl<'a> QueryParameter<'a> for f64 {
     #[cfg(feature = "postgres")]
@@ -534,6 +707,14 @@ impl<'a> QueryParameter<'a> for f64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &f64 {
     #[cfg(feature = "postgres")]
@@ -548,6 +729,14 @@ impl<'a> QueryParameter<'a> for &f64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<f64> {
     #[cfg(feature = "postgres")]
@@ -562,6 +751,14 @@ impl<'a> QueryParameter<'a> for Option<f64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&f64> {
     #[cfg(feature = "postgres")]
@@ -578,6 +775,14 @@ impl<'a> QueryParameter<'a> for Option<&f64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.copied())
+    }
 }
 impl<'a> QueryParameter<'a> for i64 {
     #[cfg(feature = "postgres")]
@@ -592,6 +797,14 @@ impl<'a> QueryParameter<'a> for i64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for &i64 {
     #[cfg(feature = "postgres")]
@@ -606,6 +819,14 @@ impl<'a> QueryParameter<'a> for &i64 {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(**self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<i64> {
     #[cfg(feature = "postgres")]
@@ -620,6 +841,14 @@ impl<'a> QueryParameter<'a> for Option<i64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&i64> {
     #[cfg(feature = "postgres")]
@@ -634,6 +863,14 @@ impl<'a> QueryParameter<'a> for Option<&i64> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.copied())
+    }
 }
 impl<'a> QueryParameter<'a> for String {
     #[cfg(feature = "postgres")]
@@ -648,6 +885,14 @@ impl<'a> QueryParameter<'a> for String {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.clone())
+    }
 }
 impl<'a> QueryParameter<'a> for &String {
     #[cfg(feature = "postgres")]
@@ -662,6 +907,14 @@ impl<'a> QueryParameter<'a> for &String {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.as_str())
+    }
 }
 impl<'a> QueryParameter<'a> for Option<String> {
     #[cfg(feature = "postgres")]
@@ -679,6 +932,14 @@ impl<'a> QueryParameter<'a> for Option<String> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.clone())
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&String> {
     #[cfg(feature = "postgres")]
@@ -696,6 +957,14 @@ impl<'a> QueryParameter<'a> for Option<&String> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(self.map(String::as_str))
+    }
 }
 impl<'a> QueryParameter<'a> for &'_ str {
     #[cfg(feature = "postgres")]
@@ -710,6 +979,14 @@ impl<'a> QueryParameter<'a> for &'_ str {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<&'_ str> {
     #[cfg(feature = "postgres")]
@@ -727,6 +1004,14 @@ impl<'a> QueryParameter<'a> for Option<&'_ str> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for NaiveDate {
     #[cfg(feature = "postgres")]
@@ -741,6 +1026,14 @@ impl<'a> QueryParameter<'a> for NaiveDate {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<NaiveDate> {
     #[cfg(feature = "postgres")]
@@ -755,6 +1048,14 @@ impl<'a> QueryParameter<'a> for Option<NaiveDate> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for NaiveTime {
     #[cfg(feature = "postgres")]
@@ -769,6 +1070,14 @@ impl<'a> QueryParameter<'a> for NaiveTime {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<NaiveTime> {
     #[cfg(feature = "postgres")]
@@ -783,6 +1092,14 @@ impl<'a> QueryParameter<'a> for Option<NaiveTime> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for NaiveDateTime {
     #[cfg(feature = "postgres")]
@@ -797,6 +1114,14 @@ impl<'a> QueryParameter<'a> for NaiveDateTime {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 impl<'a> QueryParameter<'a> for Option<NaiveDateTime> {
     #[cfg(feature = "postgres")]
@@ -811,6 +1136,14 @@ impl<'a> QueryParameter<'a> for Option<NaiveDateTime> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         self
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 
 //TODO pending
@@ -827,6 +1160,14 @@ impl<'a> QueryParameter<'a> for DateTime<FixedOffset> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 
 impl<'a> QueryParameter<'a> for Option<DateTime<FixedOffset>> {
@@ -842,6 +1183,14 @@ impl<'a> QueryParameter<'a> for Option<DateTime<FixedOffset>> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 
 impl<'a> QueryParameter<'a> for DateTime<Utc> {
@@ -857,6 +1206,14 @@ impl<'a> QueryParameter<'a> for DateTime<Utc> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
 
 impl<'a> QueryParameter<'a> for Option<DateTime<Utc>> {
@@ -872,4 +1229,12 @@ impl<'a> QueryParameter<'a> for Option<DateTime<Utc>> {
     fn as_mysql_param(&self) -> &dyn mysql_async::prelude::ToValue {
         todo!()
     }
+    #[cfg(feature = "sqlite")]
+    fn as_sqlite_param(&self) -> &dyn rusqlite::ToSql {
+        self
+    }
+    #[cfg(feature = "mock")]
+    fn as_mock_param(&self) -> MockValue {
+        MockValue::from(*self)
+    }
 }
@@ -1,15 +1,19 @@
 use async_trait::async_trait;
 use std::fmt::Display;
+use std::time::Duration;
 
 use canyon_connection::canyon_database_connector::DatabaseConnection;
-use canyon_connection::{get_database_connection, CACHED_DATABASE_CONN};
+use canyon_connection::pool::PooledConnection;
+use canyon_connection::{default_query_timeout, get_database_connection, get_read_connection};
 
 use crate::bounds::QueryParameter;
+use crate::error::CanyonError;
 use crate::mapper::RowMapper;
 use crate::query_elements::query_builder::{
     DeleteQueryBuilder, SelectQueryBuilder, UpdateQueryBuilder,
 };
 use crate::rows::CanyonRows;
+use crate::transaction::CanyonTransaction;
 
 #[cfg(feature = "mysql")]
 pub const DETECT_PARAMS_IN_QUERY: &str = r"\$([\d])+";
@@ -20,7 +24,10 @@ pub const DETECT_QUOTE_IN_QUERY: &str = r#"\"|\\"#;
 /// an statement `stmt` and the params to pass the to the client.
 ///
 /// Returns [`std::result::Result`] of [`CanyonRows`], which is the core Canyon type to wrap
-/// the result of the query provide automatic mappings and deserialization
+/// the result of the query provide automatic mappings and deserialization.
+///
+/// The queries are cancelled when they run for longer than the `query_timeout` of the
+/// configuration, if it's set, failing with a [`CanyonError::QueryTimeout`]
 #[async_trait]
 pub trait Transaction<T> {
     /// Performs a query against the targeted database by the selected or
@@ -30,38 +37,169 @@ pub trait Transaction<T> {
         stmt: S,
         params: Z,
         datasource_name: &'a str,
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>>
+    ) -> Result<CanyonRows<T>, CanyonError>
     where
         S: AsRef<str> + Display + Sync + Send + 'a,
         Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
     {
-        let mut guarded_cache = CACHED_DATABASE_CONN.lock().await;
-        let database_conn = get_database_connection(datasource_name, &mut guarded_cache);
+        let mut database_conn = get_database_connection(datasource_name)
+            .await
+            .map_err(CanyonError::from_connection_error)?;
+        launch_with_timeout::<T, Z>(
+            &mut database_conn,
+            stmt.to_string(),
+            params,
+            default_query_timeout(),
+        )
+        .await
+    }
 
-        match *database_conn {
-            #[cfg(feature = "postgres")]
-            DatabaseConnection::Postgres(_) => {
-                postgres_query_launcher::launch::<T>(
-                    database_conn,
-                    stmt.to_string(),
-                    params.as_ref(),
-                )
-                .await
-            }
-            #[cfg(feature = "mssql")]
-            DatabaseConnection::SqlServer(_) => {
-                sqlserver_query_launcher::launch::<T, Z>(
-                    database_conn,
-                    &mut stmt.to_string(),
-                    params,
-                )
-                .await
-            }
-            #[cfg(feature = "mysql")]
-            DatabaseConnection::MySQL(_) => {
-                mysql_query_launcher::launch::<T>(database_conn, stmt.to_string(), params.as_ref())
-                    .await
-            }
+    /// Performs a read-only query, sent to one of the `replicas` of the selected or
+    /// the defaulted datasource when it has them, and to the datasource itself otherwise
+    async fn query_read<'a, S, Z>(
+        stmt: S,
+        params: Z,
+        datasource_name: &'a str,
+    ) -> Result<CanyonRows<T>, CanyonError>
+    where
+        S: AsRef<str> + Display + Sync + Send + 'a,
+        Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
+    {
+        let mut database_conn = get_read_connection(datasource_name)
+            .await
+            .map_err(CanyonError::from_connection_error)?;
+        launch_with_timeout::<T, Z>(
+            &mut database_conn,
+            stmt.to_string(),
+            params,
+            default_query_timeout(),
+        )
+        .await
+    }
+
+    /// Performs a query within an open [`CanyonTransaction`], using the connection
+    /// pinned by it instead of checking out a new one from the pool of the datasource
+    async fn query_transaction<'a, S, Z>(
+        stmt: S,
+        params: Z,
+        transaction: &mut CanyonTransaction,
+    ) -> Result<CanyonRows<T>, CanyonError>
+    where
+        S: AsRef<str> + Display + Sync + Send + 'a,
+        Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
+    {
+        transaction
+            .launch::<T, Z>(stmt.to_string(), params, default_query_timeout())
+            .await
+    }
+}
+
+/// Sends the query through the given connection, cancelling it on the database when
+/// it doesn't finish within the timeout.
+///
+/// A cancelled query may leave its connection in the middle of a response, so the
+/// connection is closed once released, instead of going back to its pool
+pub(crate) async fn launch_with_timeout<'a, T, Z>(
+    database_conn: &mut PooledConnection,
+    stmt: String,
+    params: Z,
+    timeout: Option<Duration>,
+) -> Result<CanyonRows<T>, CanyonError>
+where
+    Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
+{
+    let Some(timeout) = timeout else {
+        return launch::<T, Z>(database_conn, stmt, params, None).await;
+    };
+
+    let canceller = database_conn.query_canceller(database_conn.datasource());
+    let launched = launch::<T, Z>(database_conn, stmt, params, Some(timeout));
+    if let Ok(result) = canyon_connection::tokio::time::timeout(timeout, launched).await {
+        return result;
+    }
+
+    database_conn.invalidate();
+    // The connection is closed anyway, which ends the statement on the databases
+    // that can't cancel it, so a failure to cancel it isn't reported
+    let _ = canceller.cancel().await;
+    Err(CanyonError::QueryTimeout { timeout })
+}
+
+/// Sends the query to the launcher of the database behind the given connection. The
+/// timeout is only given to the launchers of the databases embedded in the application,
+/// which must interrupt the statement by themselves
+async fn launch<'a, T, Z>(
+    database_conn: &mut DatabaseConnection,
+    stmt: String,
+    params: Z,
+    #[allow(unused_variables)] timeout: Option<Duration>,
+) -> Result<CanyonRows<T>, CanyonError>
+where
+    Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
+{
+    match *database_conn {
+        #[cfg(feature = "postgres")]
+        DatabaseConnection::Postgres(_) => {
+            postgres_query_launcher::launch::<T>(database_conn, stmt, params.as_ref()).await
+        }
+        #[cfg(feature = "mssql")]
+        DatabaseConnection::SqlServer(_) => {
+            let mut stmt = stmt;
+            sqlserver_query_launcher::launch::<T, Z>(database_conn, &mut stmt, params).await
+        }
+        #[cfg(feature = "mysql")]
+        DatabaseConnection::MySQL(_) => {
+            mysql_query_launcher::launch::<T>(database_conn, stmt, params.as_ref()).await
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseConnection::Sqlite(_) => {
+            sqlite_query_launcher::launch::<T>(database_conn, stmt, params.as_ref(), timeout).await
+        }
+        #[cfg(feature = "mock")]
+        DatabaseConnection::Mock(_) => {
+            mock_query_launcher::launch::<T>(database_conn, stmt, params.as_ref())
+        }
+    }
+}
+
+/// The statements that delimit a database transaction, and the savepoints nested
+/// within it. The SQL that every one of them represents depends on the dialect of
+/// the database, and it's written down by the launcher of each database
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum TransactionStatement<'a> {
+    Begin,
+    Commit,
+    Rollback,
+    Savepoint(&'a str),
+    RollbackTo(&'a str),
+    Release(&'a str),
+}
+
+/// Executes a [`TransactionStatement`] over the given connection
+pub(crate) async fn launch_transaction_statement(
+    database_conn: &mut DatabaseConnection,
+    statement: TransactionStatement<'_>,
+) -> Result<(), CanyonError> {
+    match *database_conn {
+        #[cfg(feature = "postgres")]
+        DatabaseConnection::Postgres(_) => {
+            postgres_query_launcher::execute_transaction_statement(database_conn, statement).await
+        }
+        #[cfg(feature = "mssql")]
+        DatabaseConnection::SqlServer(_) => {
+            sqlserver_query_launcher::execute_transaction_statement(database_conn, statement).await
+        }
+        #[cfg(feature = "mysql")]
+        DatabaseConnection::MySQL(_) => {
+            mysql_query_launcher::execute_transaction_statement(database_conn, statement).await
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseConnection::Sqlite(_) => {
+            sqlite_query_launcher::execute_transaction_statement(database_conn, statement).await
+        }
+        #[cfg(feature = "mock")]
+        DatabaseConnection::Mock(_) => {
+            mock_query_launcher::execute_transaction_statement(database_conn, statement)
         }
     }
 }
@@ -86,11 +224,13 @@ pub trait CrudOperations<T>: Transaction<T>
 where
     T: CrudOperations<T> + RowMapper<T>,
 {
-    async fn find_all<'a>() -> Result<Vec<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn find_all<'a>() -> Result<Vec<T>, CanyonError>;
 
-    async fn find_all_datasource<'a>(
-        datasource_name: &'a str,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn find_all_datasource<'a>(datasource_name: &'a str) -> Result<Vec<T>, CanyonError>;
+
+    async fn find_all_transaction(
+        transaction: &mut CanyonTransaction,
+    ) -> Result<Vec<T>, CanyonError>;
 
     async fn find_all_unchecked<'a>() -> Vec<T>;
 
@@ -100,54 +240,66 @@ where
 
     fn select_query_datasource(datasource_name: &str) -> SelectQueryBuilder<'_, T>;
 
-    async fn count() -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn count() -> Result<i64, CanyonError>;
 
-    async fn count_datasource<'a>(
-        datasource_name: &'a str,
-    ) -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn count_datasource<'a>(datasource_name: &'a str) -> Result<i64, CanyonError>;
 
-    async fn find_by_pk<'a>(
-        value: &'a dyn QueryParameter<'a>,
-    ) -> Result<Option<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn count_transaction(transaction: &mut CanyonTransaction) -> Result<i64, CanyonError>;
+
+    async fn find_by_pk<'a>(value: &'a dyn QueryParameter<'a>) -> Result<Option<T>, CanyonError>;
 
     async fn find_by_pk_datasource<'a>(
         value: &'a dyn QueryParameter<'a>,
         datasource_name: &'a str,
-    ) -> Result<Option<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<Option<T>, CanyonError>;
 
-    async fn insert<'a>(&mut self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+    async fn find_by_pk_transaction<'a>(
+        value: &'a dyn QueryParameter<'a>,
+        transaction: &mut CanyonTransaction,
+    ) -> Result<Option<T>, CanyonError>;
 
-    async fn insert_datasource<'a>(
+    async fn insert<'a>(&mut self) -> Result<(), CanyonError>;
+
+    async fn insert_datasource<'a>(&mut self, datasource_name: &'a str) -> Result<(), CanyonError>;
+
+    async fn insert_transaction(
         &mut self,
-        datasource_name: &'a str,
-    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+        transaction: &mut CanyonTransaction,
+    ) -> Result<(), CanyonError>;
 
-    async fn multi_insert<'a>(
-        instances: &'a mut [&'a mut T],
-    ) -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    async fn multi_insert<'a>(instances: &'a mut [&'a mut T]) -> Result<(), CanyonError>;
 
     async fn multi_insert_datasource<'a>(
         instances: &'a mut [&'a mut T],
         datasource_name: &'a str,
-    ) -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>>;
+    ) -> Result<(), CanyonError>;
 
-    async fn update(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+    async fn multi_insert_transaction<'a>(
+        instances: &'a mut [&'a mut T],
+        transaction: &mut CanyonTransaction,
+    ) -> Result<(), CanyonError>;
 
-    async fn update_datasource<'a>(
+    async fn update(&self) -> Result<(), CanyonError>;
+
+    async fn update_datasource<'a>(&self, datasource_name: &'a str) -> Result<(), CanyonError>;
+
+    async fn update_transaction(
         &self,
-        datasource_name: &'a str,
-    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+        transaction: &mut CanyonTransaction,
+    ) -> Result<(), CanyonError>;
 
     fn update_query<'a>() -> UpdateQueryBuilder<'a, T>;
 
     fn update_query_datasource(datasource_name: &str) -> UpdateQueryBuilder<'_, T>;
 
-    async fn delete(&self) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+    async fn delete(&self) -> Result<(), CanyonError>;
 
-    async fn delete_datasource<'a>(
+    async fn delete_datasource<'a>(&self, datasource_name: &'a str) -> Result<(), CanyonError>;
+
+    async fn delete_transaction(
         &self,
-        datasource_name: &'a str,
-    ) -> Result<(), Box<dyn std::error::Error + Sync + Send>>;
+        transaction: &mut CanyonTransaction,
+    ) -> Result<(), CanyonError>;
 
     fn delete_query<'a>() -> DeleteQueryBuilder<'a, T>;
 
@@ -159,13 +311,15 @@ mod postgres_query_launcher {
     use canyon_connection::canyon_database_connector::DatabaseConnection;
 
     use crate::bounds::QueryParameter;
+    use crate::crud::TransactionStatement;
+    use crate::error::CanyonError;
     use crate::rows::CanyonRows;
 
-    pub async fn launch<'a, T>(
+    pub async fn launch<T>(
         db_conn: &DatabaseConnection,
         stmt: String,
-        params: &'a [&'_ dyn QueryParameter<'_>],
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
+        params: &[&'_ dyn QueryParameter<'_>],
+    ) -> Result<CanyonRows<T>, CanyonError> {
         let mut m_params = Vec::new();
         for param in params {
             m_params.push(param.as_postgres_param());
@@ -179,10 +333,36 @@ mod postgres_query_launcher {
 
         Ok(CanyonRows::Postgres(r))
     }
+
+    pub fn transaction_sql(statement: TransactionStatement<'_>) -> String {
+        match statement {
+            TransactionStatement::Begin => String::from("BEGIN"),
+            TransactionStatement::Commit => String::from("COMMIT"),
+            TransactionStatement::Rollback => String::from("ROLLBACK"),
+            TransactionStatement::Savepoint(name) => format!("SAVEPOINT {name}"),
+            TransactionStatement::RollbackTo(name) => format!("ROLLBACK TO SAVEPOINT {name}"),
+            TransactionStatement::Release(name) => format!("RELEASE SAVEPOINT {name}"),
+        }
+    }
+
+    pub async fn execute_transaction_statement(
+        db_conn: &DatabaseConnection,
+        statement: TransactionStatement<'_>,
+    ) -> Result<(), CanyonError> {
+        db_conn
+            .postgres_connection()
+            .client
+            .batch_execute(&transaction_sql(statement))
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "mssql")]
 mod sqlserver_query_launcher {
+    use crate::crud::TransactionStatement;
+    use crate::error::CanyonError;
     use crate::rows::CanyonRows;
     use crate::{
         bounds::QueryParameter,
@@ -193,21 +373,23 @@ mod sqlserver_query_launcher {
         db_conn: &mut DatabaseConnection,
         stmt: &mut String,
         params: Z,
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+    ) -> Result<CanyonRows<T>, CanyonError>
     where
         Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
     {
         // Re-generate de insert statement to adequate it to the SQL SERVER syntax to retrieve the PK value(s) after insert
-        if stmt.contains("RETURNING") {
-            let c = stmt.clone();
-            let temp = c.split_once("RETURNING").unwrap();
-            let temp2 = temp.0.split_once("VALUES").unwrap();
+        if let Some((insert, returning)) = stmt.split_once("RETURNING") {
+            let (into, values) = insert.split_once("VALUES").ok_or_else(|| {
+                CanyonError::InvalidInput(String::from(
+                    "SQL Server only supports a RETURNING clause in an INSERT ... VALUES statement",
+                ))
+            })?;
 
             *stmt = format!(
                 "{} OUTPUT inserted.{} VALUES {}",
-                temp2.0.trim(),
-                temp.1.trim(),
-                temp2.1.trim()
+                into.trim(),
+                returning.trim(),
+                values.trim()
             );
         }
 
@@ -218,7 +400,7 @@ mod sqlserver_query_launcher {
             .for_each(|param| mssql_query.bind(*param));
 
         let _results = mssql_query
-            .query(db_conn.sqlserver_connection().client)
+            .query(&mut db_conn.sqlserver_connection().client)
             .await?
             .into_results()
             .await?;
@@ -227,34 +409,70 @@ mod sqlserver_query_launcher {
             _results.into_iter().flatten().collect(),
         ))
     }
+
+    pub fn transaction_sql(statement: TransactionStatement<'_>) -> String {
+        match statement {
+            TransactionStatement::Begin => String::from("BEGIN TRANSACTION"),
+            TransactionStatement::Commit => String::from("COMMIT TRANSACTION"),
+            TransactionStatement::Rollback => String::from("ROLLBACK TRANSACTION"),
+            TransactionStatement::Savepoint(name) => format!("SAVE TRANSACTION {name}"),
+            TransactionStatement::RollbackTo(name) => format!("ROLLBACK TRANSACTION {name}"),
+            // SQL Server has no way to release a savepoint, which lives until the
+            // whole transaction finishes, so there's nothing to send
+            TransactionStatement::Release(_) => String::new(),
+        }
+    }
+
+    pub async fn execute_transaction_statement(
+        db_conn: &mut DatabaseConnection,
+        statement: TransactionStatement<'_>,
+    ) -> Result<(), CanyonError> {
+        let sql = transaction_sql(statement);
+        if sql.is_empty() {
+            return Ok(());
+        }
+
+        // Sent as a plain batch, because the transaction count must outlive the
+        // `sp_executesql` calls that tiberius uses for the parametrized queries
+        db_conn
+            .sqlserver_connection()
+            .client
+            .simple_query(sql)
+            .await?
+            .into_results()
+            .await?;
+
+        Ok(())
+    }
 }
 
 #[cfg(feature = "mysql")]
 mod mysql_query_launcher {
     use std::sync::Arc;
 
-    use mysql_async::prelude::Query;
+    use mysql_async::prelude::{Query, Queryable};
     use mysql_async::QueryWithParams;
     use mysql_async::Value;
 
     use canyon_connection::canyon_database_connector::DatabaseConnection;
 
     use crate::bounds::QueryParameter;
+    use crate::error::CanyonError;
     use crate::rows::CanyonRows;
     use mysql_async::Row;
     use mysql_common::constants::ColumnType;
     use mysql_common::row;
 
     use super::reorder_params;
-    use crate::crud::{DETECT_PARAMS_IN_QUERY, DETECT_QUOTE_IN_QUERY};
+    use crate::crud::{TransactionStatement, DETECT_PARAMS_IN_QUERY, DETECT_QUOTE_IN_QUERY};
     use regex::Regex;
 
-    pub async fn launch<'a, T>(
-        db_conn: &DatabaseConnection,
+    pub async fn launch<T>(
+        db_conn: &mut DatabaseConnection,
         stmt: String,
-        params: &'a [&'_ dyn QueryParameter<'_>],
-    ) -> Result<CanyonRows<T>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-        let mysql_connection = db_conn.mysql_connection().client.get_conn().await?;
+        params: &[&'_ dyn QueryParameter<'_>],
+    ) -> Result<CanyonRows<T>, CanyonError> {
+        let mysql_connection = &mut db_conn.mysql_connection().client;
 
         let stmt_with_escape_characters = regex::escape(&stmt);
         let query_string =
@@ -271,61 +489,318 @@ mod mysql_query_launcher {
         }
 
         let params_query: Vec<Value> =
-            reorder_params(&stmt, params, |f| f.as_mysql_param().to_value());
+            reorder_params(&stmt, params, |f| f.as_mysql_param().to_value())?;
 
         let query_with_params = QueryWithParams {
             query: query_string,
             params: params_query,
         };
 
-        let mut query_result = query_with_params
-            .run(mysql_connection)
-            .await
-            .expect("Error executing query in mysql");
+        let mut query_result = query_with_params.run(mysql_connection).await?;
 
         let result_rows = if is_insert {
             let last_insert = query_result
                 .last_insert_id()
                 .map(Value::UInt)
-                .expect("Error getting pk id in insert");
+                .ok_or_else(|| {
+                    CanyonError::NotFound(String::from(
+                        "MySQL didn't return the primary key of the inserted row",
+                    ))
+                })?;
 
             vec![row::new_row(
                 vec![last_insert],
                 Arc::new([mysql_async::Column::new(ColumnType::MYSQL_TYPE_UNKNOWN)]),
             )]
         } else {
-            query_result
-                .collect::<Row>()
-                .await
-                .expect("Error resolved trait FromRow in mysql")
+            query_result.collect::<Row>().await?
         };
 
         Ok(CanyonRows::MySQL(result_rows))
     }
+
+    pub fn transaction_sql(statement: TransactionStatement<'_>) -> String {
+        match statement {
+            TransactionStatement::Begin => String::from("START TRANSACTION"),
+            TransactionStatement::Commit => String::from("COMMIT"),
+            TransactionStatement::Rollback => String::from("ROLLBACK"),
+            TransactionStatement::Savepoint(name) => format!("SAVEPOINT {name}"),
+            TransactionStatement::RollbackTo(name) => format!("ROLLBACK TO SAVEPOINT {name}"),
+            TransactionStatement::Release(name) => format!("RELEASE SAVEPOINT {name}"),
+        }
+    }
+
+    pub async fn execute_transaction_statement(
+        db_conn: &mut DatabaseConnection,
+        statement: TransactionStatement<'_>,
+    ) -> Result<(), CanyonError> {
+        db_conn
+            .mysql_connection()
+            .client
+            .query_drop(transaction_sql(statement))
+            .await?;
+
+        Ok(())
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite_query_launcher {
+    use std::sync::Arc;
+    use std::time::{Duration, Instant};
+
+    use canyon_connection::canyon_database_connector::DatabaseConnection;
+    use canyon_connection::rusqlite::{
+        self, params_from_iter,
+        types::{ToSqlOutput, Value},
+        ErrorCode, ToSql,
+    };
+    use regex::Regex;
+
+    use crate::bounds::QueryParameter;
+    use crate::crud::TransactionStatement;
+    use crate::error::CanyonError;
+    use crate::rows::{CanyonRows, SqliteRow};
+
+    /// Matches the `$1`-like placeholders of the statements generated by Canyon
+    const DETECT_NUMBERED_PARAMS: &str = r"\$(\d+)";
+
+    /// The number of virtual machine instructions between the checks of the timeout
+    const PROGRESS_HANDLER_PERIOD: i32 = 1000;
+
+    /// SQLite runs embedded in the application and blocks the thread that runs the
+    /// statement, so it's run in the threads of the runtime meant for that, over a copy
+    /// of the parameters. It's interrupted by SQLite itself when it runs for longer
+    /// than the timeout
+    pub async fn launch<T>(
+        db_conn: &DatabaseConnection,
+        stmt: String,
+        params: &[&'_ dyn QueryParameter<'_>],
+        timeout: Option<Duration>,
+    ) -> Result<CanyonRows<T>, CanyonError> {
+        let params = params
+            .iter()
+            .map(|param| owned_value(param.as_sqlite_param()))
+            .collect::<Result<Vec<Value>, _>>()?;
+
+        let rows = db_conn
+            .sqlite_connection()
+            .run_blocking(move |client| run_with_timeout(client, &stmt, &params, timeout))
+            .await?;
+
+        Ok(CanyonRows::Sqlite(rows))
+    }
+
+    /// Copies the value of a parameter, since the statement outlives the borrow of it
+    fn owned_value(param: &dyn ToSql) -> Result<Value, rusqlite::Error> {
+        match param.to_sql()? {
+            ToSqlOutput::Borrowed(value) => Ok(value.into()),
+            ToSqlOutput::Owned(value) => Ok(value),
+            _ => Err(rusqlite::Error::ToSqlConversionFailure(
+                "the parameter can't be copied into a SQLite value".into(),
+            )),
+        }
+    }
+
+    fn run_with_timeout(
+        client: &rusqlite::Connection,
+        stmt: &str,
+        params: &[Value],
+        timeout: Option<Duration>,
+    ) -> Result<Vec<SqliteRow>, CanyonError> {
+        let Some(timeout) = timeout else {
+            return run(client, stmt, params);
+        };
+
+        let started = Instant::now();
+        // The handler is called every some virtual machine instructions, and a `true`
+        // interrupts the statement
+        client.progress_handler(
+            PROGRESS_HANDLER_PERIOD,
+            Some(move || started.elapsed() >= timeout),
+        );
+        let result = run(client, stmt, params);
+        client.progress_handler(0, None::<fn() -> bool>);
+
+        result.map_err(|error| match error {
+            CanyonError::Backend(error)
+                if error
+                    .downcast_ref::<rusqlite::Error>()
+                    .is_some_and(|error| {
+                        error.sqlite_error_code() == Some(ErrorCode::OperationInterrupted)
+                    }) =>
+            {
+                CanyonError::QueryTimeout { timeout }
+            }
+            error => error,
+        })
+    }
+
+    fn run(
+        client: &rusqlite::Connection,
+        stmt: &str,
+        params: &[Value],
+    ) -> Result<Vec<SqliteRow>, CanyonError> {
+        // `?NNN` binds the parameter at the position NNN, like `$NNN` in PostgreSQL
+        let query_string = Regex::new(DETECT_NUMBERED_PARAMS)?.replace_all(stmt, "?$1");
+
+        let mut statement = client.prepare(&query_string)?;
+        let columns: Arc<[String]> = statement
+            .column_names()
+            .into_iter()
+            .map(String::from)
+            .collect();
+
+        let mut rows = statement.query(params_from_iter(params))?;
+
+        let mut results = Vec::new();
+        while let Some(row) = rows.next()? {
+            let values = (0..columns.len())
+                .map(|index| row.get::<usize, Value>(index))
+                .collect::<Result<Vec<Value>, _>>()?;
+            results.push(SqliteRow::new(columns.clone(), values));
+        }
+
+        Ok(results)
+    }
+
+    pub fn transaction_sql(statement: TransactionStatement<'_>) -> String {
+        match statement {
+            // Takes the write lock right away, so a transaction that reads before writing
+            // waits for the other writers instead of failing when it tries to write
+            TransactionStatement::Begin => String::from("BEGIN IMMEDIATE"),
+            TransactionStatement::Commit => String::from("COMMIT"),
+            TransactionStatement::Rollback => String::from("ROLLBACK"),
+            TransactionStatement::Savepoint(name) => format!("SAVEPOINT {name}"),
+            TransactionStatement::RollbackTo(name) => format!("ROLLBACK TO SAVEPOINT {name}"),
+            TransactionStatement::Release(name) => format!("RELEASE SAVEPOINT {name}"),
+        }
+    }
+
+    pub async fn execute_transaction_statement(
+        db_conn: &DatabaseConnection,
+        statement: TransactionStatement<'_>,
+    ) -> Result<(), CanyonError> {
+        let sql = transaction_sql(statement);
+        db_conn
+            .sqlite_connection()
+            .run_blocking(move |client| client.execute_batch(&sql))
+            .await?;
+
+        Ok(())
+    }
 }
 
+#[cfg(feature = "mock")]
+mod mock_query_launcher {
+    use canyon_connection::canyon_database_connector::{DatabaseConnection, DatabaseType};
+
+    use crate::bounds::QueryParameter;
+    use crate::crud::TransactionStatement;
+    use crate::error::CanyonError;
+    use crate::rows::CanyonRows;
+
+    /// Records the statement exactly as generated by Canyon, with its `$1`-like
+    /// placeholders, and returns the rows scripted for it
+    pub fn launch<T>(
+        db_conn: &DatabaseConnection,
+        stmt: String,
+        params: &[&'_ dyn QueryParameter<'_>],
+    ) -> Result<CanyonRows<T>, CanyonError> {
+        let params = params.iter().map(|param| param.as_mock_param()).collect();
+        let rows = db_conn
+            .mock_connection()
+            .database
+            .execute(stmt, params)
+            .map_err(|error| CanyonError::Backend(Box::new(error)))?;
+
+        Ok(CanyonRows::Mock(rows))
+    }
+
+    /// Records the statement as written in the dialect emulated by the mock
+    pub fn execute_transaction_statement(
+        db_conn: &DatabaseConnection,
+        statement: TransactionStatement<'_>,
+    ) -> Result<(), CanyonError> {
+        let database = &db_conn.mock_connection().database;
+        let sql = match database.dialect() {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => super::postgres_query_launcher::transaction_sql(statement),
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => super::sqlserver_query_launcher::transaction_sql(statement),
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => super::mysql_query_launcher::transaction_sql(statement),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => super::sqlite_query_launcher::transaction_sql(statement),
+        };
+        if sql.is_empty() {
+            return Ok(());
+        }
+
+        database
+            .execute(sql, Vec::new())
+            .map_err(|error| CanyonError::Backend(Box::new(error)))?;
+
+        Ok(())
+    }
+}
+
+/// Orders the parameters as their `$n` placeholders appear in the statement, which
+/// MySQL replaces by `?`, failing if a placeholder has no parameter
 #[cfg(feature = "mysql")]
 fn reorder_params<T>(
     stmt: &str,
     params: &[&'_ dyn QueryParameter<'_>],
     fn_parser: impl Fn(&&dyn QueryParameter<'_>) -> T,
-) -> Vec<T> {
-    let mut ordered_params = vec![];
-    let rg = regex::Regex::new(DETECT_PARAMS_IN_QUERY)
-        .expect("Error create regex with detect params pattern expression");
-
-    for positional_param in rg.find_iter(stmt) {
-        let pp: &str = positional_param.as_str();
-        let pp_index = pp[1..] // param $1 -> get 1
-            .parse::<usize>()
-            .expect("Error parse mapped parameter to usized.")
-            - 1;
-
-        let element = params
-            .get(pp_index)
-            .expect("Error obtaining the element of the mapping against parameters.");
-        ordered_params.push(fn_parser(element));
+) -> Result<Vec<T>, CanyonError> {
+    let rg = regex::Regex::new(DETECT_PARAMS_IN_QUERY)?;
+
+    rg.find_iter(stmt)
+        .map(|positional_param| {
+            let pp: &str = positional_param.as_str();
+            pp[1..] // param $1 -> get 1
+                .parse::<usize>()
+                .ok()
+                .and_then(|position| position.checked_sub(1))
+                .and_then(|pp_index| params.get(pp_index))
+                .map(&fn_parser)
+                .ok_or_else(|| {
+                    CanyonError::InvalidInput(format!(
+                        "The placeholder `{pp}` has no parameter, out of the {} given",
+                        params.len()
+                    ))
+                })
+        })
+        .collect()
+}
+
+#[cfg(all(test, feature = "mysql"))]
+mod crud_tests {
+    use mysql_async::Value;
+
+    use super::*;
+
+    #[test]
+    fn params_follow_the_placeholders() {
+        let (first, second) = (1, 2);
+        let params: [&dyn QueryParameter<'_>; 2] = [&first, &second];
+
+        let ordered = reorder_params("SELECT $2, $1, $2", &params, |f| {
+            f.as_mysql_param().to_value()
+        })
+        .expect("Every placeholder has a parameter");
+        assert_eq!(ordered, [Value::Int(2), Value::Int(1), Value::Int(2)]);
     }
 
-    ordered_params
+    #[test]
+    fn placeholders_without_parameter_are_rejected() {
+        let only = 1;
+        let params: [&dyn QueryParameter<'_>; 1] = [&only];
+
+        for stmt in ["SELECT $2", "SELECT $0"] {
+            let error = reorder_params(stmt, &params, |f| f.as_mysql_param().to_value())
+                .expect_err("The placeholder has no parameter");
+            assert!(matches!(error, CanyonError::InvalidInput(_)));
+        }
+    }
 }
@@ -0,0 +1,282 @@
+use std::error::Error;
+use std::fmt;
+use std::time::Duration;
+
+use canyon_connection::pool::PoolError;
+
+/// The errors that can be returned by the operations of Canyon.
+///
+/// The errors reported by the database drivers are translated into the variant that
+/// describes the kind of failure, so they can be matched without inspecting the error
+/// codes of every database. The original error is kept as the source whenever there's one
+#[derive(Debug)]
+pub enum CanyonError {
+    /// The connection with the database couldn't be established, or it was lost
+    Connection(Box<dyn Error + Send + Sync + 'static>),
+    /// The configuration of Canyon doesn't allow to perform the operation, like
+    /// when the requested datasource doesn't exist
+    Config(String),
+    /// A column of a row couldn't be converted into the type of its field
+    Mapping {
+        column: String,
+        expected_type: String,
+    },
+    /// The statement tried to store a duplicated value in a column with a unique
+    /// constraint, like a primary key
+    UniqueViolation(Box<dyn Error + Send + Sync + 'static>),
+    /// The statement tried to reference a row that doesn't exist, or to delete
+    /// one that it's still referenced by others
+    ForeignKeyViolation(Box<dyn Error + Send + Sync + 'static>),
+    /// A row that must exist wasn't returned by the database
+    NotFound(String),
+    /// No connection of the pool of the datasource was released in time
+    Timeout {
+        datasource: String,
+        waited: Duration,
+    },
+    /// The statement didn't finish within its timeout, so it was cancelled
+    QueryTimeout { timeout: Duration },
+    /// A statement of the transaction was cancelled, which may leave its connection
+    /// unusable, so the transaction can only be rolled back
+    TransactionAborted,
+    /// The operation can't be performed over the entity, like the ones that
+    /// need a primary key on a type without one
+    Unsupported(String),
+    /// Some of the arguments given to the operation aren't valid
+    InvalidInput(String),
+    /// Any other error reported by the database or by its driver
+    Backend(Box<dyn Error + Send + Sync + 'static>),
+}
+
+impl CanyonError {
+    /// Shortcut for the [`CanyonError::Mapping`] errors, used by the `CanyonMapper` derive
+    pub fn mapping(column: &str, expected_type: &str) -> Self {
+        Self::Mapping {
+            column: column.to_string(),
+            expected_type: expected_type.to_string(),
+        }
+    }
+
+    /// Translates the errors returned while checking out a connection of a pool, which
+    /// may be a [`PoolError`], or the error of the driver when opening a new connection
+    pub fn from_connection_error(error: Box<dyn Error + Send + Sync + 'static>) -> Self {
+        match error.downcast::<PoolError>() {
+            Ok(pool_error) => (*pool_error).into(),
+            Err(error) => Self::Connection(error),
+        }
+    }
+}
+
+impl fmt::Display for CanyonError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            CanyonError::Connection(e) => write!(f, "Connection error: {e}"),
+            CanyonError::Config(msg) => write!(f, "Configuration error: {msg}"),
+            CanyonError::Mapping {
+                column,
+                expected_type,
+            } => write!(
+                f,
+                "Failed to retrieve the `{column}` column as a value of type `{expected_type}`"
+            ),
+            CanyonError::UniqueViolation(e) => write!(f, "Unique constraint violated: {e}"),
+            CanyonError::ForeignKeyViolation(e) => {
+                write!(f, "Foreign key constraint violated: {e}")
+            }
+            CanyonError::NotFound(msg) => write!(f, "Not found: {msg}"),
+            CanyonError::Timeout { datasource, waited } => write!(
+                f,
+                "Timed out after {waited:?} waiting for a free connection of the datasource: {datasource}"
+            ),
+            CanyonError::QueryTimeout { timeout } => {
+                write!(f, "The query was cancelled after running for {timeout:?}")
+            }
+            CanyonError::TransactionAborted => write!(
+                f,
+                "A statement of the transaction was cancelled, so it can only be rolled back"
+            ),
+            CanyonError::Unsupported(msg) => write!(f, "Unsupported operation: {msg}"),
+            CanyonError::InvalidInput(msg) => write!(f, "Invalid input: {msg}"),
+            CanyonError::Backend(e) => write!(f, "Database error: {e}"),
+        }
+    }
+}
+
+impl Error for CanyonError {
+    fn source(&self) -> Option<&(dyn Error + 'static)> {
+        match self {
+            CanyonError::Connection(e)
+            | CanyonError::UniqueViolation(e)
+            | CanyonError::ForeignKeyViolation(e)
+            | CanyonError::Backend(e) => Some(e.as_ref()),
+            _ => None,
+        }
+    }
+}
+
+impl From<PoolError> for CanyonError {
+    fn from(error: PoolError) -> Self {
+        match error {
+            PoolError::Timeout { datasource, waited } => Self::Timeout { datasource, waited },
+            PoolError::DatasourceNotFound(_) => Self::Config(error.to_string()),
+            PoolError::Unhealthy(_) => Self::Connection(Box::new(error)),
+        }
+    }
+}
+
+impl From<regex::Error> for CanyonError {
+    fn from(error: regex::Error) -> Self {
+        Self::Backend(Box::new(error))
+    }
+}
+
+#[cfg(feature = "postgres")]
+impl From<tokio_postgres::Error> for CanyonError {
+    fn from(error: tokio_postgres::Error) -> Self {
+        use tokio_postgres::error::SqlState;
+
+        match error.code() {
+            Some(code) if *code == SqlState::UNIQUE_VIOLATION => {
+                Self::UniqueViolation(Box::new(error))
+            }
+            Some(code) if *code == SqlState::FOREIGN_KEY_VIOLATION => {
+                Self::ForeignKeyViolation(Box::new(error))
+            }
+            _ if error.is_closed() => Self::Connection(Box::new(error)),
+            _ => Self::Backend(Box::new(error)),
+        }
+    }
+}
+
+#[cfg(feature = "mssql")]
+impl From<tiberius::error::Error> for CanyonError {
+    fn from(error: tiberius::error::Error) -> Self {
+        use tiberius::error::Error as TiberiusError;
+
+        match &error {
+            // 2627: violation of a unique constraint. 2601: duplicated key on a unique index
+            TiberiusError::Server(token) if matches!(token.code(), 2627 | 2601) => {
+                Self::UniqueViolation(Box::new(error))
+            }
+            // 547: statement conflicted with a foreign key constraint
+            TiberiusError::Server(token) if token.code() == 547 => {
+                Self::ForeignKeyViolation(Box::new(error))
+            }
+            TiberiusError::Io { .. } | TiberiusError::Tls(_) | TiberiusError::Routing { .. } => {
+                Self::Connection(Box::new(error))
+            }
+            _ => Self::Backend(Box::new(error)),
+        }
+    }
+}
+
+#[cfg(feature = "mysql")]
+impl From<mysql_async::Error> for CanyonError {
+    fn from(error: mysql_async::Error) -> Self {
+        use mysql_async::Error as MysqlError;
+
+        match &error {
+            // ER_DUP_ENTRY
+            MysqlError::Server(e) if e.code == 1062 => Self::UniqueViolation(Box::new(error)),
+            // ER_ROW_IS_REFERENCED_2 and ER_NO_REFERENCED_ROW_2
+            MysqlError::Server(e) if matches!(e.code, 1451 | 1452) => {
+                Self::ForeignKeyViolation(Box::new(error))
+            }
+            MysqlError::Io(_) => Self::Connection(Box::new(error)),
+            _ => Self::Backend(Box::new(error)),
+        }
+    }
+}
+
+#[cfg(feature = "sqlite")]
+impl From<rusqlite::Error> for CanyonError {
+    fn from(error: rusqlite::Error) -> Self {
+        use rusqlite::ffi;
+
+        match &error {
+            rusqlite::Error::SqliteFailure(e, _)
+                if matches!(
+                    e.extended_code,
+                    ffi::SQLITE_CONSTRAINT_UNIQUE | ffi::SQLITE_CONSTRAINT_PRIMARYKEY
+                ) =>
+            {
+                Self::UniqueViolation(Box::new(error))
+            }
+            rusqlite::Error::SqliteFailure(e, _)
+                if e.extended_code == ffi::SQLITE_CONSTRAINT_FOREIGNKEY =>
+            {
+                Self::ForeignKeyViolation(Box::new(error))
+            }
+            rusqlite::Error::SqliteFailure(e, _) if e.code == ffi::ErrorCode::CannotOpen => {
+                Self::Connection(Box::new(error))
+            }
+            _ => Self::Backend(Box::new(error)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod canyon_error {
+    use super::*;
+
+    #[test]
+    fn pool_errors_are_translated() {
+        let timeout: CanyonError = PoolError::Timeout {
+            datasource: String::from("postgres_docker"),
+            waited: Duration::from_secs(30),
+        }
+        .into();
+        assert!(matches!(
+            timeout,
+            CanyonError::Timeout { ref datasource, waited }
+                if datasource == "postgres_docker" && waited == Duration::from_secs(30)
+        ));
+
+        let not_found = CanyonError::from_connection_error(Box::new(
+            PoolError::DatasourceNotFound(String::from("unknown")),
+        ));
+        assert!(matches!(not_found, CanyonError::Config(_)));
+    }
+
+    #[test]
+    fn driver_errors_while_connecting_are_connection_errors() {
+        let error = CanyonError::from_connection_error(Box::new(std::io::Error::new(
+            std::io::ErrorKind::ConnectionRefused,
+            "connection refused",
+        )));
+
+        assert!(matches!(error, CanyonError::Connection(_)));
+        assert!(error.source().is_some());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_constraint_errors_are_translated() {
+        let conn = rusqlite::Connection::open_in_memory().unwrap();
+        conn.execute_batch(
+            "PRAGMA foreign_keys = ON;
+            CREATE TABLE league (id INTEGER PRIMARY KEY, slug TEXT UNIQUE);
+            CREATE TABLE tournament (id INTEGER PRIMARY KEY, league INTEGER REFERENCES league(id));
+            INSERT INTO league VALUES (1, 'lec');",
+        )
+        .unwrap();
+
+        let unique: CanyonError = conn
+            .execute("INSERT INTO league VALUES (2, 'lec')", [])
+            .unwrap_err()
+            .into();
+        assert!(matches!(unique, CanyonError::UniqueViolation(_)));
+
+        let primary_key: CanyonError = conn
+            .execute("INSERT INTO league VALUES (1, 'lcs')", [])
+            .unwrap_err()
+            .into();
+        assert!(matches!(primary_key, CanyonError::UniqueViolation(_)));
+
+        let foreign_key: CanyonError = conn
+            .execute("INSERT INTO tournament VALUES (1, 42)", [])
+            .unwrap_err()
+            .into();
+        assert!(matches!(foreign_key, CanyonError::ForeignKeyViolation(_)));
+    }
+}
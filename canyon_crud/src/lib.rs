@@ -3,9 +3,11 @@ extern crate canyon_connection;
 
 pub mod bounds;
 pub mod crud;
+pub mod error;
 pub mod mapper;
 pub mod query_elements;
 pub mod rows;
+pub mod transaction;
 
 pub use query_elements::operators::*;
 
@@ -1,3 +1,5 @@
+#[cfg(feature = "mock")]
+use canyon_connection::mock::MockRow;
 #[cfg(feature = "mysql")]
 use canyon_connection::mysql_async;
 #[cfg(feature = "mssql")]
@@ -5,16 +7,268 @@ use canyon_connection::tiberius;
 #[cfg(feature = "postgres")]
 use canyon_connection::tokio_postgres;
 
-use crate::crud::Transaction;
+use crate::error::CanyonError;
+#[cfg(feature = "sqlite")]
+use crate::rows::SqliteRow;
 
 /// Declares functions that takes care to deserialize data incoming
 /// from some supported database in Canyon-SQL into a user's defined
-/// type `T`
-pub trait RowMapper<T: Transaction<T>>: Sized {
+/// type `T`.
+///
+/// Every column is retrieved as the type of its field, failing with a
+/// [`CanyonError::Mapping`] if it's missing or if it can't be converted.
+///
+/// Besides the entities, it's derived by the types that only hold some of the
+/// columns of a query, and it's implemented for the tuples of up to 8 elements
+/// of [`FromColumn`] types, which are mapped by the position of the columns
+pub trait RowMapper<T>: Sized {
     #[cfg(feature = "postgres")]
-    fn deserialize_postgresql(row: &tokio_postgres::Row) -> T;
+    fn deserialize_postgresql(row: &tokio_postgres::Row) -> Result<T, CanyonError>;
     #[cfg(feature = "mssql")]
-    fn deserialize_sqlserver(row: &tiberius::Row) -> T;
+    fn deserialize_sqlserver(row: &tiberius::Row) -> Result<T, CanyonError>;
     #[cfg(feature = "mysql")]
-    fn deserialize_mysql(row: &mysql_async::Row) -> T;
+    fn deserialize_mysql(row: &mysql_async::Row) -> Result<T, CanyonError>;
+    #[cfg(feature = "sqlite")]
+    fn deserialize_sqlite(row: &SqliteRow) -> Result<T, CanyonError>;
+    #[cfg(feature = "mock")]
+    fn deserialize_mock(row: &MockRow) -> Result<T, CanyonError>;
+}
+
+/// The types that a column can be retrieved as by its position in the row,
+/// which are the elements of the tuples that implement [`RowMapper`]
+pub trait FromColumn: Sized {
+    #[cfg(feature = "postgres")]
+    fn from_postgresql(row: &tokio_postgres::Row, index: usize) -> Result<Self, CanyonError>;
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(row: &tiberius::Row, index: usize) -> Result<Self, CanyonError>;
+    #[cfg(feature = "mysql")]
+    fn from_mysql(row: &mysql_async::Row, index: usize) -> Result<Self, CanyonError>;
+    #[cfg(feature = "sqlite")]
+    fn from_sqlite(row: &SqliteRow, index: usize) -> Result<Self, CanyonError>;
+    #[cfg(feature = "mock")]
+    fn from_mock(row: &MockRow, index: usize) -> Result<Self, CanyonError>;
+}
+
+fn mapping_error<T>(index: usize) -> CanyonError {
+    CanyonError::mapping(&index.to_string(), std::any::type_name::<T>())
+}
+
+#[cfg(feature = "postgres")]
+fn postgresql_column<T>(row: &tokio_postgres::Row, index: usize) -> Result<T, CanyonError>
+where
+    T: for<'r> tokio_postgres::types::FromSql<'r>,
+{
+    row.try_get(index).map_err(|_| mapping_error::<T>(index))
+}
+
+/// Tiberius returns `None` for the NULL values, so they only are an error
+/// when the column isn't retrieved as an `Option`
+#[cfg(feature = "mssql")]
+fn sqlserver_column<'r, T>(row: &'r tiberius::Row, index: usize) -> Result<Option<T>, CanyonError>
+where
+    T: tiberius::FromSql<'r>,
+{
+    row.try_get(index).map_err(|_| mapping_error::<T>(index))
+}
+
+#[cfg(feature = "mysql")]
+fn mysql_column<T>(row: &mysql_async::Row, index: usize) -> Result<T, CanyonError>
+where
+    T: mysql_async::prelude::FromValue,
+{
+    row.get_opt(index)
+        .and_then(|value| value.ok())
+        .ok_or_else(|| mapping_error::<T>(index))
+}
+
+#[cfg(feature = "sqlite")]
+fn sqlite_column<T>(row: &SqliteRow, index: usize) -> Result<T, CanyonError>
+where
+    T: canyon_connection::rusqlite::types::FromSql,
+{
+    row.try_get_at(index).map_err(|_| mapping_error::<T>(index))
+}
+
+#[cfg(feature = "mock")]
+fn mock_column<T>(row: &MockRow, index: usize) -> Result<T, CanyonError>
+where
+    T: canyon_connection::mock::FromMockValue,
+{
+    row.try_get_at(index).map_err(|_| mapping_error::<T>(index))
+}
+
+/// Implements [`FromColumn`] for the given types and for their `Option`, where the
+/// `String` is apart since SQL Server only retrieves it as a `&str`
+macro_rules! impl_from_column {
+    ($($ty:ty),* $(,)?) => {
+        $(
+            impl FromColumn for $ty {
+                #[cfg(feature = "postgres")]
+                fn from_postgresql(row: &tokio_postgres::Row, index: usize) -> Result<Self, CanyonError> {
+                    postgresql_column(row, index)
+                }
+                #[cfg(feature = "mssql")]
+                fn from_sqlserver(row: &tiberius::Row, index: usize) -> Result<Self, CanyonError> {
+                    sqlserver_column(row, index)?.ok_or_else(|| mapping_error::<Self>(index))
+                }
+                #[cfg(feature = "mysql")]
+                fn from_mysql(row: &mysql_async::Row, index: usize) -> Result<Self, CanyonError> {
+                    mysql_column(row, index)
+                }
+                #[cfg(feature = "sqlite")]
+                fn from_sqlite(row: &SqliteRow, index: usize) -> Result<Self, CanyonError> {
+                    sqlite_column(row, index)
+                }
+                #[cfg(feature = "mock")]
+                fn from_mock(row: &MockRow, index: usize) -> Result<Self, CanyonError> {
+                    mock_column(row, index)
+                }
+            }
+
+            impl FromColumn for Option<$ty> {
+                #[cfg(feature = "postgres")]
+                fn from_postgresql(row: &tokio_postgres::Row, index: usize) -> Result<Self, CanyonError> {
+                    postgresql_column(row, index)
+                }
+                #[cfg(feature = "mssql")]
+                fn from_sqlserver(row: &tiberius::Row, index: usize) -> Result<Self, CanyonError> {
+                    sqlserver_column(row, index)
+                }
+                #[cfg(feature = "mysql")]
+                fn from_mysql(row: &mysql_async::Row, index: usize) -> Result<Self, CanyonError> {
+                    mysql_column(row, index)
+                }
+                #[cfg(feature = "sqlite")]
+                fn from_sqlite(row: &SqliteRow, index: usize) -> Result<Self, CanyonError> {
+                    sqlite_column(row, index)
+                }
+                #[cfg(feature = "mock")]
+                fn from_mock(row: &MockRow, index: usize) -> Result<Self, CanyonError> {
+                    mock_column(row, index)
+                }
+            }
+        )*
+    };
+}
+
+impl_from_column!(
+    bool,
+    i16,
+    i32,
+    i64,
+    f32,
+    f64,
+    chrono::NaiveDate,
+    chrono::NaiveTime,
+    chrono::NaiveDateTime,
+);
+
+impl FromColumn for String {
+    #[cfg(feature = "postgres")]
+    fn from_postgresql(row: &tokio_postgres::Row, index: usize) -> Result<Self, CanyonError> {
+        postgresql_column(row, index)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(row: &tiberius::Row, index: usize) -> Result<Self, CanyonError> {
+        sqlserver_column::<&str>(row, index)?
+            .map(str::to_owned)
+            .ok_or_else(|| mapping_error::<Self>(index))
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(row: &mysql_async::Row, index: usize) -> Result<Self, CanyonError> {
+        mysql_column(row, index)
+    }
+    #[cfg(feature = "sqlite")]
+    fn from_sqlite(row: &SqliteRow, index: usize) -> Result<Self, CanyonError> {
+        sqlite_column(row, index)
+    }
+    #[cfg(feature = "mock")]
+    fn from_mock(row: &MockRow, index: usize) -> Result<Self, CanyonError> {
+        mock_column(row, index)
+    }
+}
+
+impl FromColumn for Option<String> {
+    #[cfg(feature = "postgres")]
+    fn from_postgresql(row: &tokio_postgres::Row, index: usize) -> Result<Self, CanyonError> {
+        postgresql_column(row, index)
+    }
+    #[cfg(feature = "mssql")]
+    fn from_sqlserver(row: &tiberius::Row, index: usize) -> Result<Self, CanyonError> {
+        Ok(sqlserver_column::<&str>(row, index)?.map(str::to_owned))
+    }
+    #[cfg(feature = "mysql")]
+    fn from_mysql(row: &mysql_async::Row, index: usize) -> Result<Self, CanyonError> {
+        mysql_column(row, index)
+    }
+    #[cfg(feature = "sqlite")]
+    fn from_sqlite(row: &SqliteRow, index: usize) -> Result<Self, CanyonError> {
+        sqlite_column(row, index)
+    }
+    #[cfg(feature = "mock")]
+    fn from_mock(row: &MockRow, index: usize) -> Result<Self, CanyonError> {
+        mock_column(row, index)
+    }
+}
+
+/// Implements [`RowMapper`] for a tuple of [`FromColumn`] types, whose elements
+/// are the columns of the row in the same order
+macro_rules! impl_row_mapper_for_tuple {
+    ($($name:ident => $index:tt),+) => {
+        impl<$($name: FromColumn),+> RowMapper<($($name,)+)> for ($($name,)+) {
+            #[cfg(feature = "postgres")]
+            fn deserialize_postgresql(row: &tokio_postgres::Row) -> Result<Self, CanyonError> {
+                Ok(($($name::from_postgresql(row, $index)?,)+))
+            }
+            #[cfg(feature = "mssql")]
+            fn deserialize_sqlserver(row: &tiberius::Row) -> Result<Self, CanyonError> {
+                Ok(($($name::from_sqlserver(row, $index)?,)+))
+            }
+            #[cfg(feature = "mysql")]
+            fn deserialize_mysql(row: &mysql_async::Row) -> Result<Self, CanyonError> {
+                Ok(($($name::from_mysql(row, $index)?,)+))
+            }
+            #[cfg(feature = "sqlite")]
+            fn deserialize_sqlite(row: &SqliteRow) -> Result<Self, CanyonError> {
+                Ok(($($name::from_sqlite(row, $index)?,)+))
+            }
+            #[cfg(feature = "mock")]
+            fn deserialize_mock(row: &MockRow) -> Result<Self, CanyonError> {
+                Ok(($($name::from_mock(row, $index)?,)+))
+            }
+        }
+    };
+}
+
+impl_row_mapper_for_tuple!(A => 0);
+impl_row_mapper_for_tuple!(A => 0, B => 1);
+impl_row_mapper_for_tuple!(A => 0, B => 1, C => 2);
+impl_row_mapper_for_tuple!(A => 0, B => 1, C => 2, D => 3);
+impl_row_mapper_for_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4);
+impl_row_mapper_for_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5);
+impl_row_mapper_for_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6);
+impl_row_mapper_for_tuple!(A => 0, B => 1, C => 2, D => 3, E => 4, F => 5, G => 6, H => 7);
+
+#[cfg(test)]
+mod mapper_tests {
+    use super::*;
+
+    #[cfg(feature = "mock")]
+    #[test]
+    fn tuples_are_mapped_by_position() {
+        use canyon_connection::mock::MockValue;
+
+        let row = MockRow::new([
+            ("name", MockValue::from("LCK")),
+            ("id", MockValue::from(7)),
+            ("image_url", MockValue::Null),
+        ]);
+        let tuple = <(String, i64, Option<String>)>::deserialize_mock(&row);
+        assert_eq!(tuple.ok(), Some((String::from("LCK"), 7, None)));
+
+        // A NULL column can only be retrieved as an `Option`
+        let error = <(String, i64, String)>::deserialize_mock(&row).unwrap_err();
+        assert!(matches!(error, CanyonError::Mapping { column, .. } if column == "2"));
+        assert!(<(i32, i32)>::deserialize_mock(&row).is_err());
+    }
 }
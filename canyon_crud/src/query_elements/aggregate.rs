@@ -0,0 +1,150 @@
+use canyon_connection::canyon_database_connector::DatabaseType;
+
+use crate::{
+    bounds::FieldIdentifier,
+    crud::{CrudOperations, Transaction},
+    mapper::RowMapper,
+};
+
+/// The aggregate functions of a [`super::query_builder::SelectQueryBuilder`], which
+/// are selected along with its columns, and filter its groups with a `HAVING` clause.
+///
+/// They are written so every database returns the same type for them: `COUNT`
+/// is an `i64`, `AVG` an `f64`, and `MIN` and `MAX` have the type of their column.
+/// The type of `SUM` depends on the database, since PostgreSQL widens the sum of
+/// the integers to the next type, as a `BIGINT` for an `INTEGER` column and as a
+/// `NUMERIC` for a `BIGINT` one. `AVG`, `SUM`, `MIN` and `MAX` are `NULL` when no
+/// rows are aggregated, so they should be read as an `Option`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Aggregate {
+    /// `COUNT(*)`, the number of rows
+    Count,
+    /// `SUM(column)`, the sum of the values of the column
+    Sum(&'static str),
+    /// `AVG(column)`, the mean of the values of the column
+    Avg(&'static str),
+    /// `MIN(column)`, the lowest value of the column
+    Min(&'static str),
+    /// `MAX(column)`, the greatest value of the column
+    Max(&'static str),
+}
+
+impl Aggregate {
+    /// The sum of the values of the given column
+    pub fn sum<T, Z>(column: Z) -> Self
+    where
+        T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
+        Z: FieldIdentifier<T>,
+    {
+        Self::Sum(column.as_str())
+    }
+
+    /// The mean of the values of the given column
+    pub fn avg<T, Z>(column: Z) -> Self
+    where
+        T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
+        Z: FieldIdentifier<T>,
+    {
+        Self::Avg(column.as_str())
+    }
+
+    /// The lowest value of the given column
+    pub fn min<T, Z>(column: Z) -> Self
+    where
+        T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
+        Z: FieldIdentifier<T>,
+    {
+        Self::Min(column.as_str())
+    }
+
+    /// The greatest value of the given column
+    pub fn max<T, Z>(column: Z) -> Self
+    where
+        T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
+        Z: FieldIdentifier<T>,
+    {
+        Self::Max(column.as_str())
+    }
+
+    /// The name of the aggregate in the results of the query, which is the name of the
+    /// function followed by the one of its column, like `count` or `sum_ext_id`, and
+    /// the one of the field that holds it in the types that derive `CanyonMapper`
+    pub fn alias(&self) -> String {
+        match self {
+            Self::Count => String::from("count"),
+            Self::Sum(column) => format!("sum_{column}"),
+            Self::Avg(column) => format!("avg_{column}"),
+            Self::Min(column) => format!("min_{column}"),
+            Self::Max(column) => format!("max_{column}"),
+        }
+    }
+
+    /// Writes the SQL of the aggregate in the dialect of the given database. SQL Server
+    /// counts with an `INT` and averages the integers as integers, and MySQL averages
+    /// them as a `DECIMAL`, so they are converted into a `BIGINT` and into a `FLOAT`
+    pub(crate) fn as_sql(&self, datasource_type: &DatabaseType) -> String {
+        match self {
+            Self::Count => match datasource_type {
+                #[cfg(feature = "postgres")]
+                DatabaseType::PostgreSql => String::from("COUNT(*)"),
+                #[cfg(feature = "mssql")]
+                DatabaseType::SqlServer => String::from("COUNT_BIG(*)"),
+                #[cfg(feature = "mysql")]
+                DatabaseType::MySQL => String::from("COUNT(*)"),
+                #[cfg(feature = "sqlite")]
+                DatabaseType::Sqlite => String::from("COUNT(*)"),
+            },
+            Self::Sum(column) => format!("SUM({column})"),
+            Self::Avg(column) => match datasource_type {
+                #[cfg(feature = "postgres")]
+                DatabaseType::PostgreSql => format!("CAST(AVG({column}) AS DOUBLE PRECISION)"),
+                #[cfg(feature = "mssql")]
+                DatabaseType::SqlServer => format!("AVG(CAST({column} AS FLOAT))"),
+                #[cfg(feature = "mysql")]
+                DatabaseType::MySQL => format!("CAST(AVG({column}) AS DOUBLE)"),
+                #[cfg(feature = "sqlite")]
+                DatabaseType::Sqlite => format!("AVG({column})"),
+            },
+            Self::Min(column) => format!("MIN({column})"),
+            Self::Max(column) => format!("MAX({column})"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod aggregate_tests {
+    use super::*;
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_aggregates() {
+        let db_type = DatabaseType::PostgreSql;
+
+        assert_eq!(Aggregate::Count.as_sql(&db_type), "COUNT(*)");
+        assert_eq!(Aggregate::Count.alias(), "count");
+        assert_eq!(
+            Aggregate::Avg("ext_id").as_sql(&db_type),
+            "CAST(AVG(ext_id) AS DOUBLE PRECISION)"
+        );
+        assert_eq!(Aggregate::Sum("ext_id").alias(), "sum_ext_id");
+        assert_eq!(Aggregate::Max("id").as_sql(&db_type), "MAX(id)");
+    }
+
+    #[cfg(all(feature = "mssql", feature = "mysql"))]
+    #[test]
+    fn sqlserver_and_mysql_aggregates() {
+        assert_eq!(
+            Aggregate::Count.as_sql(&DatabaseType::SqlServer),
+            "COUNT_BIG(*)"
+        );
+        assert_eq!(
+            Aggregate::Avg("id").as_sql(&DatabaseType::SqlServer),
+            "AVG(CAST(id AS FLOAT))"
+        );
+        assert_eq!(Aggregate::Count.as_sql(&DatabaseType::MySQL), "COUNT(*)");
+        assert_eq!(
+            Aggregate::Avg("id").as_sql(&DatabaseType::MySQL),
+            "CAST(AVG(id) AS DOUBLE)"
+        );
+    }
+}
@@ -0,0 +1,259 @@
+use std::marker::PhantomData;
+
+use canyon_connection::canyon_database_connector::DatabaseType;
+
+use crate::{
+    bounds::{FieldIdentifier, FieldValueIdentifier, QueryParameter},
+    crud::{CrudOperations, Transaction},
+    mapper::RowMapper,
+    Operator,
+};
+
+/// A boolean expression over the columns of the entity `T`, made of conditions
+/// that are grouped with `AND` and `OR`, and nested as needed.
+///
+/// The conditions are tuples of a [`FieldValueIdentifier`] with an [`Operator`], or
+/// the ones built by [`Expr::is_null`], [`Expr::between`] or [`Expr::values_in`] among
+/// others, and the groups are built with the [`crate::all`] and [`crate::any`] macros:
+///
+/// ```ignore
+/// // WHERE (region = $1 AND (name = $2 OR id > $3))
+/// League::select_query()
+///     .where_expr(all![
+///         (LeagueFieldValue::region(&"KOREA"), Comp::Eq),
+///         any![
+///             (LeagueFieldValue::name(&"LCK"), Comp::Eq),
+///             (LeagueFieldValue::id(&10), Comp::Gt),
+///         ],
+///     ]);
+/// ```
+pub struct Expr<'a, T> {
+    node: Node<'a>,
+    marker: PhantomData<T>,
+}
+
+enum Node<'a> {
+    Condition {
+        column: &'static str,
+        op: Box<dyn Operator + 'a>,
+        value: &'a dyn QueryParameter<'a>,
+    },
+    Null {
+        column: &'static str,
+        negated: bool,
+    },
+    Between {
+        column: &'static str,
+        low: &'a dyn QueryParameter<'a>,
+        high: &'a dyn QueryParameter<'a>,
+    },
+    In {
+        column: &'static str,
+        values: Vec<&'a dyn QueryParameter<'a>>,
+        negated: bool,
+    },
+    All(Vec<Node<'a>>),
+    Any(Vec<Node<'a>>),
+}
+
+impl<'a, T> Expr<'a, T>
+where
+    T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
+{
+    /// A condition that compares the column with the value of the
+    /// [`FieldValueIdentifier`] through the given operator
+    pub fn cond<Z: FieldValueIdentifier<'a, T>>(column: Z, op: impl Operator + 'a) -> Self {
+        let (column, value) = column.value();
+        Self::new(Node::Condition {
+            column,
+            op: Box::new(op),
+            value,
+        })
+    }
+
+    /// Holds when the column is `NULL`
+    pub fn is_null<Z: FieldIdentifier<T>>(column: Z) -> Self {
+        Self::new(Node::Null {
+            column: column.as_str(),
+            negated: false,
+        })
+    }
+
+    /// Holds when the column isn't `NULL`
+    pub fn is_not_null<Z: FieldIdentifier<T>>(column: Z) -> Self {
+        Self::new(Node::Null {
+            column: column.as_str(),
+            negated: true,
+        })
+    }
+
+    /// Holds when the column is within the given bounds, both included
+    pub fn between<Z, Q>(column: Z, low: &'a Q, high: &'a Q) -> Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        Self::new(Node::Between {
+            column: column.as_str(),
+            low,
+            high,
+        })
+    }
+
+    /// Holds when the column is one of the given values. It never holds
+    /// for an empty slice
+    pub fn values_in<Z, Q>(column: Z, values: &'a [Q]) -> Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        Self::new(Node::In {
+            column: column.as_str(),
+            values: values.iter().map(|value| value as _).collect(),
+            negated: false,
+        })
+    }
+
+    /// Holds when the column is none of the given values. It always holds
+    /// for an empty slice
+    pub fn values_not_in<Z, Q>(column: Z, values: &'a [Q]) -> Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        Self::new(Node::In {
+            column: column.as_str(),
+            values: values.iter().map(|value| value as _).collect(),
+            negated: true,
+        })
+    }
+
+    /// Holds when every one of the expressions holds. A group without
+    /// expressions always holds
+    pub fn all(exprs: impl IntoIterator<Item = Expr<'a, T>>) -> Self {
+        Self::new(Node::All(exprs.into_iter().map(|expr| expr.node).collect()))
+    }
+
+    /// Holds when any of the expressions holds. A group without
+    /// expressions never holds
+    pub fn any(exprs: impl IntoIterator<Item = Expr<'a, T>>) -> Self {
+        Self::new(Node::Any(exprs.into_iter().map(|expr| expr.node).collect()))
+    }
+
+    fn new(node: Node<'a>) -> Self {
+        Self {
+            node,
+            marker: PhantomData,
+        }
+    }
+
+    /// Writes the SQL of the expression, numbering its placeholders after the given
+    /// parameters, and pushing the values of its conditions to them in the same order.
+    /// The groups of several expressions are enclosed in parentheses, so they keep
+    /// their meaning next to the conditions chained after them
+    pub(crate) fn render(
+        &self,
+        params: &mut Vec<&'a dyn QueryParameter<'a>>,
+        datasource_type: &DatabaseType,
+    ) -> String {
+        self.node.render(params, datasource_type)
+    }
+}
+
+impl<'a> Node<'a> {
+    /// Only the groups of more than one expression are parenthesized, since they
+    /// are the ones whose precedence matters
+    fn render(
+        &self,
+        params: &mut Vec<&'a dyn QueryParameter<'a>>,
+        datasource_type: &DatabaseType,
+    ) -> String {
+        let (nodes, separator, empty) = match self {
+            Node::Condition { column, op, value } => {
+                let condition = op.as_condition(column, params.len() + 1, datasource_type);
+                params.push(*value);
+                return condition;
+            }
+            Node::Null { column, negated } => {
+                return format!("{column} IS {}NULL", if *negated { "NOT " } else { "" });
+            }
+            Node::Between { column, low, high } => {
+                let condition = format!(
+                    "{column} BETWEEN ${} AND ${}",
+                    params.len() + 1,
+                    params.len() + 2
+                );
+                params.extend([*low, *high]);
+                return condition;
+            }
+            Node::In {
+                column,
+                values,
+                negated,
+            } => {
+                // Nothing is within an empty list, which the databases don't accept
+                if values.is_empty() {
+                    return String::from(if *negated { "1 = 1" } else { "1 = 0" });
+                }
+                let placeholders = (params.len() + 1..=params.len() + values.len())
+                    .map(|placeholder| format!("${placeholder}"))
+                    .collect::<Vec<_>>();
+                params.extend(values.iter().copied());
+                return format!(
+                    "{column} {}IN ({})",
+                    if *negated { "NOT " } else { "" },
+                    placeholders.join(", ")
+                );
+            }
+            Node::All(nodes) => (nodes, " AND ", "1 = 1"),
+            Node::Any(nodes) => (nodes, " OR ", "1 = 0"),
+        };
+
+        match nodes.as_slice() {
+            [] => String::from(empty),
+            [node] => node.render(params, datasource_type),
+            nodes => {
+                let sql = nodes
+                    .iter()
+                    .map(|node| node.render(params, datasource_type))
+                    .collect::<Vec<_>>()
+                    .join(separator);
+                format!("({sql})")
+            }
+        }
+    }
+}
+
+/// A condition given as a tuple of a [`FieldValueIdentifier`] and an [`Operator`]
+impl<'a, T, Z, O> From<(Z, O)> for Expr<'a, T>
+where
+    T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
+    Z: FieldValueIdentifier<'a, T>,
+    O: Operator + 'a,
+{
+    fn from((column, op): (Z, O)) -> Self {
+        Expr::cond(column, op)
+    }
+}
+
+/// Groups the given conditions and expressions with `AND`, into an [`Expr`]
+/// that holds when all of them hold
+#[macro_export]
+macro_rules! all {
+    ($($expr:expr),* $(,)?) => {
+        $crate::query_elements::expression::Expr::all([
+            $($crate::query_elements::expression::Expr::from($expr)),*
+        ])
+    };
+}
+
+/// Groups the given conditions and expressions with `OR`, into an [`Expr`]
+/// that holds when any of them holds
+#[macro_export]
+macro_rules! any {
+    ($($expr:expr),* $(,)?) => {
+        $crate::query_elements::expression::Expr::any([
+            $($crate::query_elements::expression::Expr::from($expr)),*
+        ])
+    };
+}
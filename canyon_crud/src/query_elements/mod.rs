@@ -1,3 +1,6 @@
+pub mod aggregate;
+pub mod expression;
 pub mod operators;
+pub mod pagination;
 pub mod query;
 pub mod query_builder;
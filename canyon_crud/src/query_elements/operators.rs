@@ -2,6 +2,21 @@ use canyon_connection::canyon_database_connector::DatabaseType;
 
 pub trait Operator {
     fn as_str(&self, placeholder_counter: usize, datasource_type: &DatabaseType) -> String;
+
+    /// Writes the whole condition over the given column. The operators that
+    /// only follow the column don't need to override it, unlike the ones that
+    /// must transform the column itself for some databases
+    fn as_condition(
+        &self,
+        column: &str,
+        placeholder_counter: usize,
+        datasource_type: &DatabaseType,
+    ) -> String {
+        format!(
+            "{column}{}",
+            self.as_str(placeholder_counter, datasource_type)
+        )
+    }
 }
 
 /// Enumerated type for represent the comparison operations
@@ -43,8 +58,20 @@ pub enum Like {
     Right,
 }
 
-impl Operator for Like {
-    fn as_str(&self, placeholder_counter: usize, datasource_type: &DatabaseType) -> String {
+impl Like {
+    /// Writes the pattern that the value must match, made of the placeholder
+    /// of the value and of the wildcards of the variant
+    fn pattern(&self, placeholder_counter: usize, datasource_type: &DatabaseType) -> String {
+        // SQLite has no `CONCAT` function, so the pattern is built with its `||` operator
+        #[cfg(feature = "sqlite")]
+        if *datasource_type == DatabaseType::Sqlite {
+            return match *self {
+                Like::Full => format!("'%' || ${placeholder_counter} || '%'"),
+                Like::Left => format!("'%' || ${placeholder_counter}"),
+                Like::Right => format!("${placeholder_counter} || '%'"),
+            };
+        }
+
         let type_data_to_cast_str = match datasource_type {
             #[cfg(feature = "postgres")]
             DatabaseType::PostgreSql => "VARCHAR",
@@ -52,18 +79,170 @@ impl Operator for Like {
             DatabaseType::SqlServer => "VARCHAR",
             #[cfg(feature = "mysql")]
             DatabaseType::MySQL => "CHAR",
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => "TEXT",
         };
 
         match *self {
             Like::Full => {
-                format!(" LIKE CONCAT('%', CAST(${placeholder_counter} AS {type_data_to_cast_str}) ,'%')")
+                format!("CONCAT('%', CAST(${placeholder_counter} AS {type_data_to_cast_str}) ,'%')")
+            }
+            Like::Left => {
+                format!("CONCAT('%', CAST(${placeholder_counter} AS {type_data_to_cast_str}))")
+            }
+            Like::Right => {
+                format!("CONCAT(CAST(${placeholder_counter} AS {type_data_to_cast_str}) ,'%')")
             }
-            Like::Left => format!(
-                " LIKE CONCAT('%', CAST(${placeholder_counter} AS {type_data_to_cast_str}))"
-            ),
-            Like::Right => format!(
-                " LIKE CONCAT(CAST(${placeholder_counter} AS {type_data_to_cast_str}) ,'%')"
-            ),
         }
     }
 }
+
+impl Operator for Like {
+    fn as_str(&self, placeholder_counter: usize, datasource_type: &DatabaseType) -> String {
+        format!(
+            " LIKE {}",
+            self.pattern(placeholder_counter, datasource_type)
+        )
+    }
+}
+
+/// The negation of [`Like`], for the values that don't match the pattern
+pub enum NotLike {
+    /// Operator "NOT LIKE"  as '%pattern%'
+    Full,
+    /// Operator "NOT LIKE"  as '%pattern'
+    Left,
+    /// Operator "NOT LIKE"  as 'pattern%'
+    Right,
+}
+
+impl Operator for NotLike {
+    fn as_str(&self, placeholder_counter: usize, datasource_type: &DatabaseType) -> String {
+        let like = match *self {
+            NotLike::Full => Like::Full,
+            NotLike::Left => Like::Left,
+            NotLike::Right => Like::Right,
+        };
+        format!(
+            " NOT LIKE {}",
+            like.pattern(placeholder_counter, datasource_type)
+        )
+    }
+}
+
+/// The case-insensitive form of [`Like`]. It's the `ILIKE` of PostgreSQL, while
+/// SQL Server compares the lowercased column and pattern, and MySQL the pattern
+/// with a case-insensitive collation. The `LIKE` of SQLite already ignores the
+/// case of the ASCII characters
+///
+/// The lowercased column of SQL Server is only written by [`Operator::as_condition`],
+/// so [`Operator::as_str`] alone isn't a complete condition for that database
+pub enum ILike {
+    /// Operator "ILIKE"  as '%pattern%'
+    Full,
+    /// Operator "ILIKE"  as '%pattern'
+    Left,
+    /// Operator "ILIKE"  as 'pattern%'
+    Right,
+}
+
+impl ILike {
+    /// The collation of MySQL that the pattern is compared with
+    pub const MYSQL_COLLATION: &'static str = "utf8mb4_general_ci";
+}
+
+impl Operator for ILike {
+    fn as_str(&self, placeholder_counter: usize, datasource_type: &DatabaseType) -> String {
+        let like = match *self {
+            ILike::Full => Like::Full,
+            ILike::Left => Like::Left,
+            ILike::Right => Like::Right,
+        };
+        let pattern = like.pattern(placeholder_counter, datasource_type);
+
+        match datasource_type {
+            #[cfg(feature = "postgres")]
+            DatabaseType::PostgreSql => format!(" ILIKE {pattern}"),
+            #[cfg(feature = "mssql")]
+            DatabaseType::SqlServer => format!(" LIKE LOWER({pattern})"),
+            #[cfg(feature = "mysql")]
+            DatabaseType::MySQL => format!(" LIKE {pattern} COLLATE {}", Self::MYSQL_COLLATION),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => format!(" LIKE {pattern}"),
+        }
+    }
+
+    fn as_condition(
+        &self,
+        column: &str,
+        placeholder_counter: usize,
+        datasource_type: &DatabaseType,
+    ) -> String {
+        let condition = self.as_str(placeholder_counter, datasource_type);
+
+        #[cfg(feature = "mssql")]
+        if *datasource_type == DatabaseType::SqlServer {
+            return format!("LOWER({column}){condition}");
+        }
+
+        format!("{column}{condition}")
+    }
+}
+
+#[cfg(test)]
+mod operators_tests {
+    use super::*;
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_like_operators() {
+        let db_type = DatabaseType::PostgreSql;
+
+        assert_eq!(
+            Like::Full.as_condition("name", 1, &db_type),
+            "name LIKE CONCAT('%', CAST($1 AS VARCHAR) ,'%')"
+        );
+        assert_eq!(
+            NotLike::Right.as_condition("name", 2, &db_type),
+            "name NOT LIKE CONCAT(CAST($2 AS VARCHAR) ,'%')"
+        );
+        assert_eq!(
+            ILike::Left.as_condition("name", 3, &db_type),
+            "name ILIKE CONCAT('%', CAST($3 AS VARCHAR))"
+        );
+        assert_eq!(Comp::GtEq.as_condition("id", 4, &db_type), "id >= $4");
+    }
+
+    #[cfg(feature = "mssql")]
+    #[test]
+    fn sqlserver_case_insensitive_like() {
+        assert_eq!(
+            ILike::Full.as_condition("name", 1, &DatabaseType::SqlServer),
+            "LOWER(name) LIKE LOWER(CONCAT('%', CAST($1 AS VARCHAR) ,'%'))"
+        );
+    }
+
+    #[cfg(feature = "mysql")]
+    #[test]
+    fn mysql_case_insensitive_like() {
+        assert_eq!(
+            ILike::Right.as_condition("name", 1, &DatabaseType::MySQL),
+            "name LIKE CONCAT(CAST($1 AS CHAR) ,'%') COLLATE utf8mb4_general_ci"
+        );
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_like_operators() {
+        let db_type = DatabaseType::Sqlite;
+
+        assert_eq!(
+            NotLike::Full.as_condition("name", 1, &db_type),
+            "name NOT LIKE '%' || $1 || '%'"
+        );
+        assert_eq!(
+            ILike::Right.as_condition("name", 1, &db_type),
+            "name LIKE $1 || '%'"
+        );
+    }
+}
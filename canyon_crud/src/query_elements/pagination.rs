@@ -0,0 +1,211 @@
+use canyon_connection::canyon_database_connector::DatabaseType;
+
+use crate::{error::CanyonError, rows::CanyonRows};
+
+/// A page of the results of a [`super::query_builder::SelectQueryBuilder`], along with
+/// the number of rows that match the filters of the query
+#[derive(Debug, Clone, PartialEq)]
+pub struct Page<T> {
+    /// The rows of the page
+    pub items: Vec<T>,
+    /// The rows that match the filters, in every page, or the groups of a grouped
+    /// query. The `after` cursor isn't considered a filter, so it doesn't change the total
+    pub total: u64,
+    /// The rows skipped before the first one of the page
+    pub offset: u64,
+    /// The maximum number of rows of the page, if the query is limited
+    pub limit: Option<u64>,
+    /// Whether a row was found after the last one of the page
+    pub(crate) next: bool,
+}
+
+impl<T> Page<T> {
+    /// Whether there are more rows after the ones of this page. The page is read with
+    /// one more row than its limit to know it, so it's also right when paging with an
+    /// `after` cursor, whose rows aren't counted by the `offset` nor by the `total`
+    pub fn has_next(&self) -> bool {
+        self.next
+    }
+
+    /// The number of pages of the size of this one needed for all the rows
+    pub fn pages(&self) -> Option<u64> {
+        match self.limit {
+            Some(0) => None,
+            Some(limit) => Some(self.total.div_ceil(limit)),
+            None => Some(1),
+        }
+    }
+}
+
+/// Writes the clause that skips the first `offset` rows of a query and limits it to `limit`
+/// rows, in the dialect of the given database. SQL Server only paginates the ordered queries,
+/// so the ones that aren't get an `ORDER BY` that keeps the order of the database.
+///
+/// Every database but MySQL takes them as a signed `BIGINT`, so they're bounded to it
+#[cfg_attr(not(feature = "mssql"), allow(unused_variables))]
+pub(crate) fn pagination_clause(
+    db_type: DatabaseType,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    ordered: bool,
+) -> String {
+    if limit.is_none() && offset.is_none() {
+        return String::new();
+    }
+
+    let mut clause = String::new();
+    match db_type {
+        #[cfg(feature = "postgres")]
+        DatabaseType::PostgreSql => {
+            let (limit, offset) = (limit.map(bigint), offset.map(bigint));
+            if let Some(limit) = limit {
+                clause.push_str(&format!(" LIMIT {limit}"));
+            }
+            if let Some(offset) = offset {
+                clause.push_str(&format!(" OFFSET {offset}"));
+            }
+        }
+        #[cfg(feature = "mssql")]
+        DatabaseType::SqlServer => {
+            let (limit, offset) = (limit.map(bigint), offset.map(bigint));
+            if !ordered {
+                clause.push_str(" ORDER BY (SELECT NULL)");
+            }
+            clause.push_str(&format!(" OFFSET {} ROWS", offset.unwrap_or(0)));
+            if let Some(limit) = limit {
+                clause.push_str(&format!(" FETCH NEXT {limit} ROWS ONLY"));
+            }
+        }
+        // MySQL and SQLite only take the offset after a limit, so the unlimited
+        // queries get the largest one that they allow
+        #[cfg(feature = "mysql")]
+        DatabaseType::MySQL => {
+            clause.push_str(&format!(" LIMIT {}", limit.unwrap_or(u64::MAX)));
+            if let Some(offset) = offset {
+                clause.push_str(&format!(" OFFSET {offset}"));
+            }
+        }
+        #[cfg(feature = "sqlite")]
+        DatabaseType::Sqlite => {
+            let (limit, offset) = (limit.map(bigint), offset.map(bigint));
+            match limit {
+                Some(limit) => clause.push_str(&format!(" LIMIT {limit}")),
+                None => clause.push_str(" LIMIT -1"),
+            }
+            if let Some(offset) = offset {
+                clause.push_str(&format!(" OFFSET {offset}"));
+            }
+        }
+    }
+
+    clause
+}
+
+/// The given number, or the greatest `BIGINT` if it's greater
+#[cfg(any(feature = "postgres", feature = "mssql", feature = "sqlite"))]
+fn bigint(n: u64) -> u64 {
+    n.min(i64::MAX as u64)
+}
+
+/// Retrieves the result of a `SELECT COUNT(*)`
+pub(crate) fn row_count<T>(rows: CanyonRows<T>) -> Result<u64, CanyonError> {
+    let count: Option<i64> = match &rows {
+        #[cfg(feature = "postgres")]
+        CanyonRows::Postgres(v) => v.first().and_then(|row| row.try_get(0).ok()),
+        #[cfg(feature = "mssql")]
+        CanyonRows::Tiberius(v) => v
+            .first()
+            .and_then(|row| row.try_get::<i32, usize>(0).ok().flatten())
+            .map(i64::from),
+        #[cfg(feature = "mysql")]
+        CanyonRows::MySQL(v) => v
+            .first()
+            .and_then(|row| row.get_opt::<i64, usize>(0))
+            .and_then(|count| count.ok()),
+        #[cfg(feature = "sqlite")]
+        CanyonRows::Sqlite(v) => v.first().and_then(|row| row.try_get_at(0).ok()),
+        #[cfg(feature = "mock")]
+        CanyonRows::Mock(v) => v.first().and_then(|row| row.try_get_at(0).ok()),
+        CanyonRows::UnusableTypeMarker(_) => None,
+    };
+
+    count
+        .and_then(|count| u64::try_from(count).ok())
+        .ok_or_else(|| CanyonError::mapping("COUNT(*)", "i64"))
+}
+
+#[cfg(test)]
+mod pagination_tests {
+    use super::*;
+
+    #[cfg(feature = "postgres")]
+    #[test]
+    fn postgres_pagination() {
+        let clause =
+            |limit, offset| pagination_clause(DatabaseType::PostgreSql, limit, offset, false);
+
+        assert_eq!(clause(None, None), "");
+        assert_eq!(clause(Some(50), None), " LIMIT 50");
+        assert_eq!(clause(Some(50), Some(100)), " LIMIT 50 OFFSET 100");
+        assert_eq!(clause(None, Some(100)), " OFFSET 100");
+        assert_eq!(
+            clause(Some(u64::MAX), Some(u64::MAX)),
+            " LIMIT 9223372036854775807 OFFSET 9223372036854775807"
+        );
+    }
+
+    #[cfg(feature = "mssql")]
+    #[test]
+    fn sqlserver_pagination() {
+        assert_eq!(
+            pagination_clause(DatabaseType::SqlServer, Some(50), Some(100), true),
+            " OFFSET 100 ROWS FETCH NEXT 50 ROWS ONLY"
+        );
+        assert_eq!(
+            pagination_clause(DatabaseType::SqlServer, Some(50), None, false),
+            " ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 50 ROWS ONLY"
+        );
+        assert_eq!(
+            pagination_clause(DatabaseType::SqlServer, None, Some(100), true),
+            " OFFSET 100 ROWS"
+        );
+    }
+
+    #[cfg(all(feature = "mysql", feature = "sqlite"))]
+    #[test]
+    fn mysql_and_sqlite_pagination() {
+        assert_eq!(
+            pagination_clause(DatabaseType::MySQL, Some(50), Some(100), false),
+            " LIMIT 50 OFFSET 100"
+        );
+        assert_eq!(
+            pagination_clause(DatabaseType::MySQL, None, Some(100), false),
+            " LIMIT 18446744073709551615 OFFSET 100"
+        );
+        assert_eq!(
+            pagination_clause(DatabaseType::Sqlite, None, Some(100), false),
+            " LIMIT -1 OFFSET 100"
+        );
+    }
+
+    #[test]
+    fn page_navigation() {
+        let page = Page {
+            items: vec![1, 2, 3],
+            total: 7,
+            offset: 3,
+            limit: Some(3),
+            next: true,
+        };
+        assert!(page.has_next());
+        assert_eq!(page.pages(), Some(3));
+
+        let last = Page {
+            items: vec![7],
+            offset: 6,
+            next: false,
+            ..page
+        };
+        assert!(!last.has_next());
+    }
+}
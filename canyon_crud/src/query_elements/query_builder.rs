@@ -1,14 +1,24 @@
 use std::fmt::Debug;
+use std::time::Duration;
 
 use canyon_connection::{
-    canyon_database_connector::DatabaseType, get_database_config, DATASOURCES,
+    canyon_database_connector::DatabaseType, default_query_timeout, get_database_connection,
+    get_database_type, get_read_connection, pool::PooledConnection,
 };
 
 use crate::{
     bounds::{FieldIdentifier, FieldValueIdentifier, QueryParameter},
-    crud::{CrudOperations, Transaction},
+    crud::{launch_with_timeout, CrudOperations, Transaction},
+    error::CanyonError,
     mapper::RowMapper,
-    query_elements::query::Query,
+    query_elements::{
+        aggregate::Aggregate,
+        expression::Expr,
+        pagination::{pagination_clause, row_count, Page},
+        query::Query,
+    },
+    rows::CanyonRows,
+    transaction::CanyonTransaction,
     Operator,
 };
 
@@ -77,6 +87,37 @@ pub mod ops {
         where
             T: Debug + CrudOperations<T> + Transaction<T> + RowMapper<T>;
 
+        /// Generates a `WHERE` SQL clause with a boolean expression, whose groups
+        /// are enclosed in parentheses, so `(a OR b) AND c` keeps its meaning.
+        ///
+        /// * `expr` - An [`Expr`] with the conditions of the filter, usually
+        ///   grouped with the [`crate::all`] and [`crate::any`] macros
+        fn where_expr(&mut self, expr: Expr<'a, T>) -> &mut Self;
+
+        /// Generates an `AND` SQL clause with a boolean expression, enclosed in
+        /// parentheses when it groups several conditions
+        ///
+        /// * `expr` - An [`Expr`] with the conditions of the filter
+        fn and_expr(&mut self, expr: Expr<'a, T>) -> &mut Self;
+
+        /// Generates an `OR` SQL clause with a boolean expression, enclosed in
+        /// parentheses when it groups several conditions
+        ///
+        /// * `expr` - An [`Expr`] with the conditions of the filter
+        fn or_expr(&mut self, expr: Expr<'a, T>) -> &mut Self;
+
+        /// Generates a `WHERE` SQL clause with an `IN` operator, so the filter
+        /// can start by it. Nothing is within an empty slice of values
+        ///
+        /// * `column` - A [`FieldIdentifier`] that will provide the target
+        ///   column name for the filter
+        /// * `values` - An array of [`QueryParameter`] with the values to filter
+        ///   inside the `IN` operator
+        fn where_in<Z, Q>(&mut self, column: Z, values: &'a [Q]) -> &mut Self
+        where
+            Z: FieldIdentifier<T>,
+            Q: QueryParameter<'a>;
+
         /// Generates an `AND` SQL clause for constraint the query.
         ///
         /// * `column` - A [`FieldValueIdentifier`] that will provide the target
@@ -129,6 +170,11 @@ pub mod ops {
         /// * `order_by` - A [`FieldIdentifier`] that will provide the target  column name
         /// * `desc` - a boolean indicating if the generated `ORDER_BY` must be in ascending or descending order
         fn order_by<Z: FieldIdentifier<T>>(&mut self, order_by: Z, desc: bool) -> &mut Self;
+
+        /// Bounds the time that the query can run, instead of the `query_timeout` of the
+        /// configuration. The query is cancelled on the database when it doesn't finish
+        /// in time, and it fails with a [`CanyonError::QueryTimeout`]
+        fn timeout(&mut self, timeout: Duration) -> &mut Self;
     }
 }
 
@@ -141,6 +187,12 @@ where
     query: Query<'a, T>,
     datasource_name: &'a str,
     datasource_type: DatabaseType,
+    read_from_replicas: bool,
+    timeout: Option<Duration>,
+    /// Where the `ORDER BY` clause starts in the SQL, if the query is ordered
+    order_by_at: Option<usize>,
+    /// The first column the query is ordered by, and whether it's descending
+    order_by: Option<(&'static str, bool)>,
 }
 
 unsafe impl<'a, T> Send for QueryBuilder<'a, T> where
@@ -157,49 +209,127 @@ where
     T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
 {
     /// Returns a new instance of the [`QueryBuilder`]
+    ///
+    /// # Panics
+    /// If there's no registered datasource with the given name
     pub fn new(query: Query<'a, T>, datasource_name: &'a str) -> Self {
         Self {
             query,
             datasource_name,
-            datasource_type: DatabaseType::from(
-                &get_database_config(datasource_name, &DATASOURCES).auth,
-            ),
+            datasource_type: get_database_type(datasource_name)
+                .unwrap_or_else(|error| panic!("{error}")),
+            read_from_replicas: false,
+            timeout: None,
+            order_by_at: None,
+            order_by: None,
         }
     }
 
     /// Launches the generated query against the database targeted
-    /// by the selected datasource
-    pub async fn query(
-        &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    /// by the selected datasource, or against one of its replicas if
+    /// the query only reads
+    pub async fn query(&'a mut self) -> Result<Vec<T>, CanyonError> {
+        self.query_as::<T>().await
+    }
+
+    /// Launches the generated query like [`QueryBuilder::query`], mapping the
+    /// resultant rows into `R` instead of into the entity
+    pub async fn query_as<R: RowMapper<R>>(&'a mut self) -> Result<Vec<R>, CanyonError> {
         self.query.sql.push(';');
 
-        Ok(T::query(
+        let mut connection = self.connection().await?;
+        self.launch(
+            &mut connection,
             self.query.sql.clone(),
             self.query.params.to_vec(),
-            self.datasource_name,
         )
         .await?
-        .into_results::<T>())
+        .into_results_as::<R>()
+    }
+
+    /// Launches the generated query within the given transaction,
+    /// through the connection pinned by it
+    pub async fn query_transaction(
+        &'a mut self,
+        transaction: &mut CanyonTransaction,
+    ) -> Result<Vec<T>, CanyonError> {
+        self.query.sql.push(';');
+
+        transaction
+            .launch(
+                self.query.sql.clone(),
+                self.query.params.to_vec(),
+                self.query_timeout(),
+            )
+            .await?
+            .into_results::<T>()
+    }
+
+    /// Takes a connection of the selected datasource, or of one of its replicas
+    /// if the query only reads
+    async fn connection(&self) -> Result<PooledConnection, CanyonError> {
+        let connection = if self.read_from_replicas {
+            get_read_connection(self.datasource_name).await
+        } else {
+            get_database_connection(self.datasource_name).await
+        };
+        connection.map_err(CanyonError::from_connection_error)
+    }
+
+    /// Launches the given statement through the given connection, bounded by
+    /// the timeout of the query
+    async fn launch(
+        &self,
+        connection: &mut PooledConnection,
+        sql: String,
+        params: Vec<&'a dyn QueryParameter<'a>>,
+    ) -> Result<CanyonRows<T>, CanyonError> {
+        launch_with_timeout::<T, _>(connection, sql, params, self.query_timeout()).await
+    }
+
+    /// The timeout of the query, or the `query_timeout` of the configuration
+    fn query_timeout(&self) -> Option<Duration> {
+        self.timeout.or_else(default_query_timeout)
     }
 
     pub fn r#where<Z: FieldValueIdentifier<'a, T>>(&mut self, r#where: Z, op: impl Operator) {
         let (column_name, value) = r#where.value();
 
         let where_ = String::from(" WHERE ")
-            + column_name
-            + &op.as_str(self.query.params.len() + 1, &self.datasource_type);
+            + &op.as_condition(
+                column_name,
+                self.query.params.len() + 1,
+                &self.datasource_type,
+            );
 
         self.query.sql.push_str(&where_);
         self.query.params.push(value);
     }
 
+    pub fn where_expr(&mut self, expr: Expr<'a, T>) {
+        let where_ = expr.render(&mut self.query.params, &self.datasource_type);
+        self.query.sql.push_str(&format!(" WHERE {where_}"));
+    }
+
+    pub fn and_expr(&mut self, expr: Expr<'a, T>) {
+        let and_ = expr.render(&mut self.query.params, &self.datasource_type);
+        self.query.sql.push_str(&format!(" AND {and_}"));
+    }
+
+    pub fn or_expr(&mut self, expr: Expr<'a, T>) {
+        let or_ = expr.render(&mut self.query.params, &self.datasource_type);
+        self.query.sql.push_str(&format!(" OR {or_}"));
+    }
+
     pub fn and<Z: FieldValueIdentifier<'a, T>>(&mut self, r#and: Z, op: impl Operator) {
         let (column_name, value) = r#and.value();
 
         let and_ = String::from(" AND ")
-            + column_name
-            + &op.as_str(self.query.params.len() + 1, &self.datasource_type);
+            + &op.as_condition(
+                column_name,
+                self.query.params.len() + 1,
+                &self.datasource_type,
+            );
 
         self.query.sql.push_str(&and_);
         self.query.params.push(value);
@@ -209,8 +339,11 @@ where
         let (column_name, value) = r#and.value();
 
         let and_ = String::from(" OR ")
-            + column_name
-            + &op.as_str(self.query.params.len() + 1, &self.datasource_type);
+            + &op.as_condition(
+                column_name,
+                self.query.params.len() + 1,
+                &self.datasource_type,
+            );
 
         self.query.sql.push_str(&and_);
         self.query.params.push(value);
@@ -280,6 +413,8 @@ where
 
     #[inline]
     pub fn order_by<Z: FieldIdentifier<T>>(&mut self, order_by: Z, desc: bool) {
+        self.order_by_at.get_or_insert(self.query.sql.len());
+        self.order_by.get_or_insert((order_by.as_str(), desc));
         self.query.sql.push_str(
             &(format!(
                 " ORDER BY {}{}",
@@ -290,12 +425,30 @@ where
     }
 }
 
+/// Contains the specific database operations of the *SELECT* SQL statements.
+///
+/// Besides the joins, it paginates the results, either by skipping a number of
+/// rows with [`SelectQueryBuilder::limit`], [`SelectQueryBuilder::offset`] and
+/// [`SelectQueryBuilder::page`], or with the stable cursor of
+/// [`SelectQueryBuilder::after`]. They are written at the end of the SQL when
+/// the query is launched, in the dialect of its database.
+///
+/// The columns of the query can be narrowed with [`SelectQueryBuilder::select`],
+/// along with the [`Aggregate`]s of its rows, which are grouped with
+/// [`SelectQueryBuilder::group_by`] and filtered with [`SelectQueryBuilder::having`],
+/// and its rows mapped into another type with [`SelectQueryBuilder::query_as`]
 #[derive(Debug, Clone)]
 pub struct SelectQueryBuilder<'a, T>
 where
     T: CrudOperations<T> + Transaction<T> + RowMapper<T>,
 {
     _inner: QueryBuilder<'a, T>,
+    columns: Vec<String>,
+    group_by: Vec<&'static str>,
+    having: Vec<String>,
+    limit: Option<u64>,
+    offset: Option<u64>,
+    after: Option<(&'static str, &'a dyn QueryParameter<'a>)>,
 }
 
 impl<'a, T> SelectQueryBuilder<'a, T>
@@ -304,21 +457,323 @@ where
 {
     /// Generates a new public instance of the [`SelectQueryBuilder`]
     pub fn new(table_schema_data: &str, datasource_name: &'a str) -> Self {
+        let mut _inner = QueryBuilder::<T>::new(
+            Query::new(format!("SELECT * FROM {table_schema_data}")),
+            datasource_name,
+        );
+        _inner.read_from_replicas = true;
+
         Self {
-            _inner: QueryBuilder::<T>::new(
-                Query::new(format!("SELECT * FROM {table_schema_data}")),
-                datasource_name,
-            ),
+            _inner,
+            columns: Vec::new(),
+            group_by: Vec::new(),
+            having: Vec::new(),
+            limit: None,
+            offset: None,
+            after: None,
         }
     }
 
     /// Launches the generated query to the database pointed by the
-    /// selected datasource
-    #[inline]
-    pub async fn query(
+    /// selected datasource, or to one of its replicas if it has them
+    pub async fn query(&'a mut self) -> Result<Vec<T>, CanyonError> {
+        self.query_as::<T>().await
+    }
+
+    /// Launches the generated query like [`SelectQueryBuilder::query`], mapping the
+    /// resultant rows into `R`, which is usually a type that derives `CanyonMapper`
+    /// with the selected columns, or a tuple of them in the same order
+    pub async fn query_as<R: RowMapper<R>>(&'a mut self) -> Result<Vec<R>, CanyonError> {
+        self.check_cursor()?;
+        let mut connection = self._inner.connection().await?;
+        self._inner
+            .launch(&mut connection, self.sql() + ";", self.params())
+            .await?
+            .into_results_as::<R>()
+    }
+
+    /// Only retrieves the given columns instead of all of them, so the
+    /// rows must be mapped into a type that only holds them with
+    /// [`SelectQueryBuilder::query_as`]. Every column is retrieved while
+    /// neither columns nor aggregates are selected, unless the query is
+    /// grouped, which then retrieves the columns of its groups
+    ///
+    /// * `columns` - The [`FieldIdentifier`]s of the columns, which are returned
+    ///   in the same order, after the ones and the aggregates already selected
+    pub fn select<Z: FieldIdentifier<T>>(&mut self, columns: &[Z]) -> &mut Self {
+        self.columns
+            .extend(columns.iter().map(|column| column.as_str().to_string()));
+        self
+    }
+
+    /// Selects the given [`Aggregate`], named after its [`Aggregate::alias`], after
+    /// the columns and the aggregates already selected
+    pub fn aggregate(&mut self, aggregate: Aggregate) -> &mut Self {
+        let sql = aggregate.as_sql(&self._inner.datasource_type);
+        self.columns.push(format!("{sql} AS {}", aggregate.alias()));
+        self
+    }
+
+    /// Selects the number of rows, as an `i64` named `count`
+    pub fn count(&mut self) -> &mut Self {
+        self.aggregate(Aggregate::Count)
+    }
+
+    /// Selects the sum of the values of the column, named like `sum_<column>`
+    pub fn sum<Z: FieldIdentifier<T>>(&mut self, column: Z) -> &mut Self {
+        self.aggregate(Aggregate::Sum(column.as_str()))
+    }
+
+    /// Selects the mean of the values of the column, as an `f64` named like `avg_<column>`
+    pub fn avg<Z: FieldIdentifier<T>>(&mut self, column: Z) -> &mut Self {
+        self.aggregate(Aggregate::Avg(column.as_str()))
+    }
+
+    /// Selects the lowest value of the column, named like `min_<column>`
+    pub fn min<Z: FieldIdentifier<T>>(&mut self, column: Z) -> &mut Self {
+        self.aggregate(Aggregate::Min(column.as_str()))
+    }
+
+    /// Selects the greatest value of the column, named like `max_<column>`
+    pub fn max<Z: FieldIdentifier<T>>(&mut self, column: Z) -> &mut Self {
+        self.aggregate(Aggregate::Max(column.as_str()))
+    }
+
+    /// Generates a `GROUP BY` SQL clause, so the selected aggregates are computed
+    /// over the rows of each group instead of over all of them
+    ///
+    /// * `columns` - The [`FieldIdentifier`]s of the columns of the groups, which
+    ///   are the ones selected when no other columns or aggregates are
+    pub fn group_by<Z: FieldIdentifier<T>>(&mut self, columns: &[Z]) -> &mut Self {
+        self.group_by
+            .extend(columns.iter().map(|column| column.as_str()));
+        self
+    }
+
+    /// Generates a `HAVING` SQL clause, which only keeps the groups whose aggregate
+    /// satisfies the condition. Several of them are combined with `AND`
+    ///
+    /// * `aggregate` - The [`Aggregate`] compared, which doesn't need to be selected
+    /// * `op` - Any element that implements [`Operator`] for create the comparison
+    /// * `value` - The [`QueryParameter`] that the aggregate is compared with
+    pub fn having<Q: QueryParameter<'a>>(
+        &mut self,
+        aggregate: Aggregate,
+        op: impl Operator,
+        value: &'a Q,
+    ) -> &mut Self {
+        let datasource_type = self._inner.datasource_type;
+        self.having.push(op.as_condition(
+            &aggregate.as_sql(&datasource_type),
+            self._inner.query.params.len() + 1,
+            &datasource_type,
+        ));
+        self._inner.query.params.push(value);
+        self
+    }
+
+    /// Launches the generated query, along with a count of the rows that match
+    /// its filters, or of its groups if it's grouped, through the same connection
+    pub async fn query_page(&'a mut self) -> Result<Page<T>, CanyonError> {
+        self.query_page_as::<T>().await
+    }
+
+    /// Launches the generated query like [`SelectQueryBuilder::query_page`], mapping
+    /// the rows of the page into `R`
+    pub async fn query_page_as<R: RowMapper<R>>(&'a mut self) -> Result<Page<R>, CanyonError> {
+        self.check_cursor()?;
+        let mut connection = self._inner.connection().await?;
+        // One more row than the limit tells if there's a next page
+        let limit = self.limit.map(|limit| limit.saturating_add(1));
+        let mut items = self
+            ._inner
+            .launch(
+                &mut connection,
+                self.paginated_sql(limit) + ";",
+                self.params(),
+            )
+            .await?
+            .into_results_as::<R>()?;
+        let next = self.limit.is_some_and(|limit| items.len() as u64 > limit);
+        if let Some(limit) = self.limit {
+            items.truncate(usize::try_from(limit).unwrap_or(usize::MAX));
+        }
+
+        // The groups are counted instead of the rows when the query is grouped
+        let count = if self.group_by.is_empty() && self.having.is_empty() {
+            self.filters().replacen("SELECT *", "SELECT COUNT(*)", 1) + ";"
+        } else {
+            format!(
+                "SELECT COUNT(*) FROM ({}) AS page_groups;",
+                self.body(false)
+            )
+        };
+        let total = row_count(
+            self._inner
+                .launch(&mut connection, count, self._inner.query.params.to_vec())
+                .await?,
+        )?;
+
+        Ok(Page {
+            items,
+            total,
+            offset: self.offset.unwrap_or(0),
+            limit: self.limit,
+            next,
+        })
+    }
+
+    /// Limits the query to the given number of rows
+    pub fn limit(&mut self, limit: u64) -> &mut Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    /// Skips the given number of rows before the first one returned
+    pub fn offset(&mut self, offset: u64) -> &mut Self {
+        self.offset = Some(offset);
+        self
+    }
+
+    /// Returns the page with the given number, starting by 0, of the pages
+    /// with `size` rows. The query should be ordered, so the pages don't overlap
+    pub fn page(&mut self, page: u64, size: u64) -> &mut Self {
+        self.limit = Some(size);
+        self.offset = Some(page.saturating_mul(size));
+        self
+    }
+
+    /// Only returns the rows that come after the given value of the column, ordered
+    /// by that column unless the query is already ordered. Paging with the last
+    /// value of the previous page, instead of with an offset, gives stable pages
+    /// even when rows are inserted or deleted between them
+    ///
+    /// The rows after the cursor are the greater ones, or the lower ones if the
+    /// query is in descending order. An ordered query must be ordered first by the
+    /// column of the cursor, otherwise it's rejected when launched
+    ///
+    /// * `column` - A [`FieldIdentifier`] with the column of the cursor, usually
+    ///   the primary key or another unique one
+    /// * `value` - The value of the column in the last row already read
+    pub fn after<Z, Q>(&mut self, column: Z, value: &'a Q) -> &mut Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        self.after = Some((column.as_str(), value));
+        self
+    }
+
+    /// Returns the SQL sent to the database by [`SelectQueryBuilder::query`], which
+    /// unlike [`ops::QueryBuilder::read_sql`] contains the cursor and the pagination
+    pub fn sql(&self) -> String {
+        self.paginated_sql(self.limit)
+    }
+
+    /// Rejects a cursor on another column than the first one the query is ordered by,
+    /// whose pages would skip or repeat rows
+    fn check_cursor(&self) -> Result<(), CanyonError> {
+        match (self.after, self._inner.order_by) {
+            (Some((cursor, _)), Some((column, _))) if cursor != column => {
+                Err(CanyonError::InvalidInput(format!(
+                    "The cursor on `{cursor}` needs the query to be ordered by it first, \
+                    instead of by `{column}`"
+                )))
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// The SQL of [`SelectQueryBuilder::sql`], limited to the given number of rows
+    fn paginated_sql(&self, limit: Option<u64>) -> String {
+        let mut sql = self.body(true);
+        match (self._inner.order_by_at, self.after) {
+            (Some(at), _) => sql.push_str(self._inner.query.sql[at..].trim_end()),
+            (None, Some((column, _))) => sql.push_str(&format!(" ORDER BY {column}")),
+            (None, None) => {}
+        }
+        let ordered = self._inner.order_by_at.is_some() || self.after.is_some();
+        sql.push_str(&pagination_clause(
+            self._inner.datasource_type,
+            limit,
+            self.offset,
+            ordered,
+        ));
+
+        sql
+    }
+
+    /// The SQL of the query with the selected columns, its filters, along with the cursor
+    /// if asked for, and its groups, but without its `ORDER BY` and its pagination
+    fn body(&self, cursor: bool) -> String {
+        // A grouped query can only select the columns of its groups and aggregates
+        let columns = if self.columns.is_empty() {
+            self.group_by.join(", ")
+        } else {
+            self.columns.join(", ")
+        };
+        let mut sql = if columns.is_empty() {
+            self.filters().to_string()
+        } else {
+            self.filters()
+                .replacen("SELECT *", &format!("SELECT {columns}"), 1)
+        };
+        if let (Some((column, _)), true) = (self.after, cursor) {
+            let descending = self._inner.order_by.is_some_and(|(_, desc)| desc);
+            let condition = format!(
+                "{column} {} ${}",
+                if descending { "<" } else { ">" },
+                self._inner.query.params.len() + 1
+            );
+            match sql.find(" WHERE ") {
+                Some(at) => {
+                    sql.insert(at + " WHERE ".len(), '(');
+                    sql.push_str(&format!(") AND {condition}"));
+                }
+                None => sql.push_str(&format!(" WHERE {condition}")),
+            }
+        }
+
+        if !self.group_by.is_empty() {
+            sql.push_str(&format!(" GROUP BY {}", self.group_by.join(", ")));
+        }
+        if !self.having.is_empty() {
+            sql.push_str(&format!(" HAVING {}", self.having.join(" AND ")));
+        }
+
+        sql
+    }
+
+    /// The SQL of the query up to its `ORDER BY`, which has all its filters
+    fn filters(&self) -> &str {
+        let sql = &self._inner.query.sql;
+        &sql[..self._inner.order_by_at.unwrap_or(sql.len())]
+    }
+
+    /// The parameters of the filters, followed by the value of the cursor
+    fn params(&self) -> Vec<&'a dyn QueryParameter<'a>> {
+        let mut params = self._inner.query.params.clone();
+        params.extend(self.after.map(|(_, value)| value));
+        params
+    }
+
+    /// Sends the query to the selected datasource even if it has replicas,
+    /// for the reads that must see the latest writes
+    pub fn on_primary(&mut self) -> &mut Self {
+        self._inner.read_from_replicas = false;
+        self
+    }
+
+    /// Launches the generated query within the given transaction
+    pub async fn query_transaction(
         &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
-        self._inner.query().await
+        transaction: &mut CanyonTransaction,
+    ) -> Result<Vec<T>, CanyonError> {
+        self.check_cursor()?;
+        transaction
+            .launch(self.sql() + ";", self.params(), self._inner.query_timeout())
+            .await?
+            .into_results::<T>()
     }
 
     /// Adds a *LEFT JOIN* SQL statement to the underlying
@@ -410,6 +865,34 @@ where
         self
     }
 
+    #[inline]
+    fn where_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.where_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn and_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.and_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn or_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.or_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn where_in<Z, Q>(&mut self, column: Z, values: &'a [Q]) -> &mut Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        self._inner.where_expr(Expr::values_in(column, values));
+        self
+    }
+
     #[inline]
     fn and<Z: FieldValueIdentifier<'a, T>>(&mut self, column: Z, op: impl Operator) -> &mut Self {
         self._inner.and(column, op);
@@ -447,6 +930,12 @@ where
         self._inner.order_by(order_by, desc);
         self
     }
+
+    #[inline]
+    fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self._inner.timeout = Some(timeout);
+        self
+    }
 }
 
 /// Contains the specific database operations of the *UPDATE* SQL statements.
@@ -478,12 +967,25 @@ where
     /// Launches the generated query to the database pointed by the
     /// selected datasource
     #[inline]
-    pub async fn query(
-        &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    pub async fn query(&'a mut self) -> Result<Vec<T>, CanyonError> {
         self._inner.query().await
     }
 
+    /// Launches the generated query, mapping the rows that it returns into `R`
+    #[inline]
+    pub async fn query_as<R: RowMapper<R>>(&'a mut self) -> Result<Vec<R>, CanyonError> {
+        self._inner.query_as::<R>().await
+    }
+
+    /// Launches the generated query within the given transaction
+    #[inline]
+    pub async fn query_transaction(
+        &'a mut self,
+        transaction: &mut CanyonTransaction,
+    ) -> Result<Vec<T>, CanyonError> {
+        self._inner.query_transaction(transaction).await
+    }
+
     /// Creates an SQL `SET` clause to especify the columns that must be updated in the sentence
     pub fn set<Z, Q>(&mut self, columns: &'a [(Z, Q)]) -> &mut Self
     where
@@ -547,6 +1049,34 @@ where
         self
     }
 
+    #[inline]
+    fn where_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.where_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn and_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.and_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn or_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.or_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn where_in<Z, Q>(&mut self, column: Z, values: &'a [Q]) -> &mut Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        self._inner.where_expr(Expr::values_in(column, values));
+        self
+    }
+
     #[inline]
     fn and<Z: FieldValueIdentifier<'a, T>>(&mut self, column: Z, op: impl Operator) -> &mut Self {
         self._inner.and(column, op);
@@ -584,6 +1114,12 @@ where
         self._inner.order_by(order_by, desc);
         self
     }
+
+    #[inline]
+    fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self._inner.timeout = Some(timeout);
+        self
+    }
 }
 
 /// Contains the specific database operations associated with the
@@ -616,11 +1152,24 @@ where
     /// Launches the generated query to the database pointed by the
     /// selected datasource
     #[inline]
-    pub async fn query(
-        &'a mut self,
-    ) -> Result<Vec<T>, Box<(dyn std::error::Error + Sync + Send + 'static)>> {
+    pub async fn query(&'a mut self) -> Result<Vec<T>, CanyonError> {
         self._inner.query().await
     }
+
+    /// Launches the generated query, mapping the rows that it returns into `R`
+    #[inline]
+    pub async fn query_as<R: RowMapper<R>>(&'a mut self) -> Result<Vec<R>, CanyonError> {
+        self._inner.query_as::<R>().await
+    }
+
+    /// Launches the generated query within the given transaction
+    #[inline]
+    pub async fn query_transaction(
+        &'a mut self,
+        transaction: &mut CanyonTransaction,
+    ) -> Result<Vec<T>, CanyonError> {
+        self._inner.query_transaction(transaction).await
+    }
 }
 
 impl<'a, T> ops::QueryBuilder<'a, T> for DeleteQueryBuilder<'a, T>
@@ -647,6 +1196,34 @@ where
         self
     }
 
+    #[inline]
+    fn where_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.where_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn and_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.and_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn or_expr(&mut self, expr: Expr<'a, T>) -> &mut Self {
+        self._inner.or_expr(expr);
+        self
+    }
+
+    #[inline]
+    fn where_in<Z, Q>(&mut self, column: Z, values: &'a [Q]) -> &mut Self
+    where
+        Z: FieldIdentifier<T>,
+        Q: QueryParameter<'a>,
+    {
+        self._inner.where_expr(Expr::values_in(column, values));
+        self
+    }
+
     #[inline]
     fn and<Z: FieldValueIdentifier<'a, T>>(&mut self, column: Z, op: impl Operator) -> &mut Self {
         self._inner.and(column, op);
@@ -684,4 +1261,10 @@ where
         self._inner.order_by(order_by, desc);
         self
     }
+
+    #[inline]
+    fn timeout(&mut self, timeout: Duration) -> &mut Self {
+        self._inner.timeout = Some(timeout);
+        self
+    }
 }
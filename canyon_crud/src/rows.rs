@@ -1,6 +1,17 @@
 use crate::crud::Transaction;
+use crate::error::CanyonError;
 use crate::mapper::RowMapper;
 use std::marker::PhantomData;
+#[cfg(feature = "sqlite")]
+use std::sync::Arc;
+
+#[cfg(feature = "mock")]
+use canyon_connection::mock::MockRow;
+#[cfg(feature = "sqlite")]
+use canyon_connection::rusqlite::{
+    self,
+    types::{FromSql, FromSqlError, Value, ValueRef},
+};
 
 /// Lightweight wrapper over the collection of results of the different crates
 /// supported by Canyon-SQL.
@@ -15,6 +26,10 @@ pub enum CanyonRows<T> {
     Tiberius(Vec<tiberius::Row>),
     #[cfg(feature = "mysql")]
     MySQL(Vec<mysql_async::Row>),
+    #[cfg(feature = "sqlite")]
+    Sqlite(Vec<SqliteRow>),
+    #[cfg(feature = "mock")]
+    Mock(Vec<MockRow>),
 
     UnusableTypeMarker(PhantomData<T>),
 }
@@ -44,11 +59,40 @@ impl<T> CanyonRows<T> {
         }
     }
 
-    /// Consumes `self` and returns the wrapped [`std::vec::Vec`] with the instances of T
-    pub fn into_results<Z: RowMapper<T>>(self) -> Vec<T>
+    #[cfg(feature = "sqlite")]
+    pub fn get_sqlite_rows(&self) -> &Vec<SqliteRow> {
+        match self {
+            Self::Sqlite(v) => v,
+            _ => panic!("This branch will never ever should be reachable"),
+        }
+    }
+
+    #[cfg(feature = "mock")]
+    pub fn get_mock_rows(&self) -> &Vec<MockRow> {
+        match self {
+            Self::Mock(v) => v,
+            _ => panic!("This branch will never ever should be reachable"),
+        }
+    }
+
+    /// Consumes `self` and returns the wrapped [`std::vec::Vec`] with the instances of T,
+    /// or the error of the first row that can't be mapped into T
+    pub fn into_results<Z: RowMapper<T>>(self) -> Result<Vec<T>, CanyonError>
     where
         T: Transaction<T>,
     {
+        self.map_rows::<T, Z>()
+    }
+
+    /// Consumes `self` and returns the rows mapped into another type than the one of the
+    /// query, like the ones that only hold some of its columns, or a tuple of them
+    pub fn into_results_as<R: RowMapper<R>>(self) -> Result<Vec<R>, CanyonError> {
+        self.map_rows::<R, R>()
+    }
+
+    /// Maps every row into an R with the [`RowMapper`] Z, stopping at the first row
+    /// that can't be mapped
+    fn map_rows<R, Z: RowMapper<R>>(self) -> Result<Vec<R>, CanyonError> {
         match self {
             #[cfg(feature = "postgres")]
             Self::Postgres(v) => v.iter().map(|row| Z::deserialize_postgresql(row)).collect(),
@@ -56,6 +100,10 @@ impl<T> CanyonRows<T> {
             Self::Tiberius(v) => v.iter().map(|row| Z::deserialize_sqlserver(row)).collect(),
             #[cfg(feature = "mysql")]
             Self::MySQL(v) => v.iter().map(|row| Z::deserialize_mysql(row)).collect(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(v) => v.iter().map(|row| Z::deserialize_sqlite(row)).collect(),
+            #[cfg(feature = "mock")]
+            Self::Mock(v) => v.iter().map(|row| Z::deserialize_mock(row)).collect(),
             _ => panic!("This branch will never ever should be reachable"),
         }
     }
@@ -69,6 +117,10 @@ impl<T> CanyonRows<T> {
             Self::Tiberius(v) => v.len(),
             #[cfg(feature = "mysql")]
             Self::MySQL(v) => v.len(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(v) => v.len(),
+            #[cfg(feature = "mock")]
+            Self::Mock(v) => v.len(),
             _ => panic!("This branch will never ever should be reachable"),
         }
     }
@@ -82,7 +134,71 @@ impl<T> CanyonRows<T> {
             Self::Tiberius(v) => v.is_empty(),
             #[cfg(feature = "mysql")]
             Self::MySQL(v) => v.is_empty(),
+            #[cfg(feature = "sqlite")]
+            Self::Sqlite(v) => v.is_empty(),
+            #[cfg(feature = "mock")]
+            Self::Mock(v) => v.is_empty(),
             _ => panic!("This branch will never ever should be reachable"),
         }
     }
 }
+
+/// A row of the results of a query made against SQLite.
+///
+/// The rows of [`rusqlite`] borrow the statement that produced them, so the values
+/// are copied out of it, along with the names of the columns, to be handled like
+/// the rows of the other databases
+#[cfg(feature = "sqlite")]
+#[derive(Debug, Clone)]
+pub struct SqliteRow {
+    columns: Arc<[String]>,
+    values: Vec<Value>,
+}
+
+#[cfg(feature = "sqlite")]
+impl SqliteRow {
+    pub(crate) fn new(columns: Arc<[String]>, values: Vec<Value>) -> Self {
+        Self { columns, values }
+    }
+
+    /// The names of the columns of the row
+    pub fn columns(&self) -> &[String] {
+        &self.columns
+    }
+
+    /// The raw values of the row, in the same order as its columns
+    pub fn values(&self) -> &[Value] {
+        &self.values
+    }
+
+    /// Retrieves the value of the column with the given name, converted into `T`
+    pub fn try_get<T: FromSql>(&self, column: &str) -> Result<T, rusqlite::Error> {
+        let index = self
+            .columns
+            .iter()
+            .position(|name| name == column)
+            .ok_or_else(|| rusqlite::Error::InvalidColumnName(column.to_string()))?;
+
+        self.try_get_at(index)
+    }
+
+    /// Retrieves the value of the column at the given position, converted into `T`
+    pub fn try_get_at<T: FromSql>(&self, index: usize) -> Result<T, rusqlite::Error> {
+        let value = self
+            .values
+            .get(index)
+            .ok_or(rusqlite::Error::InvalidColumnIndex(index))?;
+
+        T::column_result(ValueRef::from(value)).map_err(|error| match error {
+            FromSqlError::InvalidType => rusqlite::Error::InvalidColumnType(
+                index,
+                self.columns[index].clone(),
+                value.data_type(),
+            ),
+            FromSqlError::OutOfRange(i) => rusqlite::Error::IntegralValueOutOfRange(index, i),
+            error => {
+                rusqlite::Error::FromSqlConversionFailure(index, value.data_type(), Box::new(error))
+            }
+        })
+    }
+}
@@ -0,0 +1,219 @@
+use std::time::Duration;
+
+use canyon_connection::canyon_database_connector::DatabaseType;
+use canyon_connection::get_database_connection;
+use canyon_connection::pool::PooledConnection;
+
+use crate::bounds::QueryParameter;
+use crate::crud::{launch_transaction_statement, launch_with_timeout, TransactionStatement};
+use crate::error::CanyonError;
+use crate::rows::CanyonRows;
+
+/// Opens a new [`CanyonTransaction`] against the datasource with the given name,
+/// or against the default one if the name is empty
+pub async fn transaction(datasource_name: &str) -> Result<CanyonTransaction, CanyonError> {
+    CanyonTransaction::begin(datasource_name).await
+}
+
+/// A database transaction, that pins one connection of the pool of a
+/// datasource from the moment that it begins until it's finished.
+///
+/// Every `_transaction` variant of the [`crate::crud::CrudOperations`], and
+/// the `query_transaction` of the query builders, runs its statement through
+/// the pinned connection, so all of them are committed or discarded together.
+///
+/// Nested units of work can have their own rollback scope by setting savepoints
+/// with [`CanyonTransaction::savepoint`].
+///
+/// The transaction must be explicitly finished with [`CanyonTransaction::commit`].
+/// Otherwise, it will be rolled back when dropped.
+///
+/// A statement cancelled by its timeout aborts the transaction, since its connection
+/// may be left unusable. The later statements, and the commit, fail with a
+/// [`CanyonError::TransactionAborted`], so it can only be rolled back or dropped.
+///
+/// ```ignore
+/// let mut tx = canyon_sql::transaction("").await?;
+///
+/// new_league.insert_transaction(&mut tx).await?;
+/// new_player.insert_transaction(&mut tx).await?;
+///
+/// tx.commit().await?;
+/// ```
+pub struct CanyonTransaction {
+    conn: Option<PooledConnection>,
+    aborted: bool,
+}
+
+impl CanyonTransaction {
+    /// Checks out a connection from the pool of the datasource with the given name,
+    /// or from the default one if the name is empty, and begins a transaction on it
+    pub async fn begin(datasource_name: &str) -> Result<Self, CanyonError> {
+        let mut conn = get_database_connection(datasource_name)
+            .await
+            .map_err(CanyonError::from_connection_error)?;
+        launch_transaction_statement(&mut conn, TransactionStatement::Begin).await?;
+
+        Ok(Self {
+            conn: Some(conn),
+            aborted: false,
+        })
+    }
+
+    /// The name of the datasource where the transaction is running
+    pub fn datasource_name(&self) -> &str {
+        &self.pooled_connection().datasource().name
+    }
+
+    /// The kind of database where the transaction is running
+    pub fn database_type(&self) -> DatabaseType {
+        self.pooled_connection().datasource().get_db_type()
+    }
+
+    /// Makes permanent all the changes made within the transaction
+    pub async fn commit(mut self) -> Result<(), CanyonError> {
+        self.finish(TransactionStatement::Commit).await
+    }
+
+    /// Discards all the changes made within the transaction
+    pub async fn rollback(mut self) -> Result<(), CanyonError> {
+        self.finish(TransactionStatement::Rollback).await
+    }
+
+    /// Sets a savepoint with the given name within the transaction, so the changes made
+    /// from now on can be discarded with [`CanyonTransaction::rollback_to`] without
+    /// losing the previous ones. Savepoints can be nested by giving them different names.
+    ///
+    /// The name must be a plain identifier: letters, digits and underscores, not
+    /// starting with a digit
+    pub async fn savepoint(&mut self, name: &str) -> Result<(), CanyonError> {
+        check_savepoint_name(name)?;
+        launch_transaction_statement(self.connection()?, TransactionStatement::Savepoint(name))
+            .await
+    }
+
+    /// Discards the changes made since the savepoint with the given name was set,
+    /// including any other savepoint set after it. The savepoint itself is kept,
+    /// so it can be rolled back to again
+    pub async fn rollback_to(&mut self, name: &str) -> Result<(), CanyonError> {
+        check_savepoint_name(name)?;
+        launch_transaction_statement(self.connection()?, TransactionStatement::RollbackTo(name))
+            .await
+    }
+
+    /// Forgets the savepoint with the given name, keeping the changes made since it
+    /// was set as part of the transaction.
+    ///
+    /// SQL Server has no way to release a savepoint, so there this is a no-op
+    pub async fn release(&mut self, name: &str) -> Result<(), CanyonError> {
+        check_savepoint_name(name)?;
+        launch_transaction_statement(self.connection()?, TransactionStatement::Release(name)).await
+    }
+
+    /// Sends a statement through the connection pinned by the transaction, aborting
+    /// the transaction if the statement is cancelled by its timeout
+    pub(crate) async fn launch<'a, T, Z>(
+        &mut self,
+        stmt: String,
+        params: Z,
+        timeout: Option<Duration>,
+    ) -> Result<CanyonRows<T>, CanyonError>
+    where
+        Z: AsRef<[&'a dyn QueryParameter<'a>]> + Sync + Send + 'a,
+    {
+        let result = launch_with_timeout::<T, Z>(self.connection()?, stmt, params, timeout).await;
+        if let Err(CanyonError::QueryTimeout { .. }) = result {
+            self.aborted = true;
+        }
+        result
+    }
+
+    /// The connection pinned by the transaction, unless the transaction was aborted
+    fn connection(&mut self) -> Result<&mut PooledConnection, CanyonError> {
+        if self.aborted {
+            return Err(CanyonError::TransactionAborted);
+        }
+        Ok(self
+            .conn
+            .as_mut()
+            .expect("A transaction only releases its connection when it's finished"))
+    }
+
+    fn pooled_connection(&self) -> &PooledConnection {
+        self.conn
+            .as_ref()
+            .expect("A transaction only releases its connection when it's finished")
+    }
+
+    /// Sends the final statement of the transaction, giving its connection back
+    /// to the pool only if the transaction was closed successfully. The connection
+    /// of an aborted transaction is closed instead, which discards the transaction
+    /// on the server side
+    async fn finish(&mut self, statement: TransactionStatement<'_>) -> Result<(), CanyonError> {
+        let mut conn = self
+            .conn
+            .take()
+            .expect("A transaction only releases its connection when it's finished");
+
+        if self.aborted {
+            conn.discard();
+            return match statement {
+                TransactionStatement::Rollback => Ok(()),
+                _ => Err(CanyonError::TransactionAborted),
+            };
+        }
+
+        let result = launch_transaction_statement(&mut conn, statement).await;
+        if result.is_err() {
+            conn.discard();
+        }
+
+        result
+    }
+}
+
+impl Drop for CanyonTransaction {
+    fn drop(&mut self) {
+        if let Some(mut conn) = self.conn.take() {
+            if self.aborted {
+                conn.discard();
+                return;
+            }
+            // The rollback can't be awaited here, so it's sent in the background. The
+            // connection goes back to the pool once the rollback succeeds, and it's closed
+            // otherwise, or if there's no runtime available to send it, which discards the
+            // transaction on the server side anyway
+            match canyon_connection::tokio::runtime::Handle::try_current() {
+                Ok(runtime) => {
+                    runtime.spawn(async move {
+                        if launch_transaction_statement(&mut conn, TransactionStatement::Rollback)
+                            .await
+                            .is_err()
+                        {
+                            conn.discard();
+                        }
+                    });
+                }
+                Err(_) => conn.discard(),
+            }
+        }
+    }
+}
+
+/// The savepoint names are written straight into the SQL sent to the database,
+/// so only plain identifiers are accepted
+fn check_savepoint_name(name: &str) -> Result<(), CanyonError> {
+    let mut chars = name.chars();
+    let is_identifier = chars
+        .next()
+        .is_some_and(|first| first.is_ascii_alphabetic() || first == '_')
+        && chars.all(|c| c.is_ascii_alphanumeric() || c == '_');
+
+    if is_identifier {
+        Ok(())
+    } else {
+        Err(CanyonError::InvalidInput(format!(
+            "`{name}` is not a valid savepoint name"
+        )))
+    }
+}
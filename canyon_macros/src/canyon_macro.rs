@@ -1,15 +1,18 @@
 //! Provides helpers to build the `#[canyon_macros::canyon]` procedural like attribute macro
 #![cfg(feature = "migrations")]
 
-use canyon_connection::CANYON_TOKIO_RUNTIME;
+use canyon_connection::canyon_runtime;
 use canyon_migrations::migrations::handler::Migrations;
 use canyon_migrations::{CM_QUERIES_TO_EXECUTE, QUERIES_TO_EXECUTE};
 use proc_macro2::TokenStream;
 use quote::quote;
 
 pub fn main_with_queries() -> TokenStream {
-    CANYON_TOKIO_RUNTIME.block_on(async {
-        canyon_connection::init_connections_cache().await;
+    canyon_runtime().block_on(async {
+        // The migrations need every datasource of the configuration
+        canyon_connection::init_connections_cache()
+            .await
+            .unwrap_or_else(|error| panic!("{error}"));
         Migrations::migrate().await;
     });
 
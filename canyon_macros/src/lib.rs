@@ -11,7 +11,7 @@ mod utils;
 use canyon_entity_macro::parse_canyon_entity_proc_macro_attr;
 use proc_macro::TokenStream as CompilerTokenStream;
 use proc_macro2::{Ident, TokenStream};
-use quote::quote;
+use quote::{quote, ToTokens};
 use syn::{DeriveInput, Fields, Type, Visibility};
 
 use query_operations::{
@@ -38,7 +38,12 @@ use canyon_entities::{
 /// Macro for handling the entry point to the program.
 ///
 /// Avoids the user to write the tokio proc_attribute and
-/// the async modifier to the main fn()
+/// the async modifier to the main fn(), running it on the runtime of Canyon,
+/// configured by the `runtime` table of the configuration.
+///
+/// An `async fn main()` is left to the attribute of its own runtime instead, like
+/// `#[tokio::main]` or `#[actix_web::main]` placed below this one, so Canyon runs
+/// within that runtime without creating another one.
 ///
 /// Also, takes care about wire the necessary code that Canyon's need
 /// to run in order to check the provided code and in order to perform
@@ -54,6 +59,7 @@ pub fn main(_meta: CompilerTokenStream, input: CompilerTokenStream) -> CompilerT
     let func = func_res.ok().unwrap();
     let sign = func.sig;
     let body = func.block.stmts;
+    let attrs = func.attrs;
 
     #[allow(unused_mut, unused_assignments)]
     let mut migrations_tokens = quote! {};
@@ -62,17 +68,31 @@ pub fn main(_meta: CompilerTokenStream, input: CompilerTokenStream) -> CompilerT
         migrations_tokens = main_with_queries();
     }
 
+    let main_body = quote! {
+        // The datasources that can't connect yet will do it when they're used
+        if let Err(error) = canyon_sql::runtime::init_connections_cache().await {
+            eprintln!("{error}");
+        }
+        #migrations_tokens
+        #(#body)*
+    };
+
     // The final code wired in main()
-    quote! {
-        #sign {
-            canyon_sql::runtime::CANYON_TOKIO_RUNTIME
-                .handle()
-                .block_on( async {
-                    canyon_sql::runtime::init_connections_cache().await;
-                    #migrations_tokens
-                    #(#body)*
-                }
-            )
+    if sign.asyncness.is_some() {
+        quote! {
+            #(#attrs)*
+            #sign {
+                #main_body
+            }
+        }
+    } else {
+        quote! {
+            #(#attrs)*
+            #sign {
+                canyon_sql::runtime::canyon_runtime().block_on(async {
+                    #main_body
+                })
+            }
         }
     }
     .into()
@@ -98,10 +118,11 @@ pub fn canyon_tokio_test(
             #[test]
             #(#attrs)*
             #sign {
-                canyon_sql::runtime::CANYON_TOKIO_RUNTIME
-                    .handle()
+                canyon_sql::runtime::canyon_runtime()
                     .block_on( async {
-                        canyon_sql::runtime::init_connections_cache().await;
+                        if let Err(error) = canyon_sql::runtime::init_connections_cache().await {
+                            eprintln!("{error}");
+                        }
                         #(#body)*
                     });
             }
@@ -448,99 +469,93 @@ pub fn implement_row_mapper_for_type(input: proc_macro::TokenStream) -> proc_mac
     #[cfg(feature = "postgres")]
     // Here it's where the incoming values of the DatabaseResult are wired into a new
     // instance, mapping the fields of the type against the columns
-    let init_field_values = fields.iter().map(|(_vis, ident, _ty)| {
+    let init_field_values = fields.iter().map(|(_vis, ident, ty)| {
         let ident_name = ident.to_string();
+        let ty_name = get_field_type_as_string(ty).replace(' ', "");
         quote! {
             #ident: row.try_get(#ident_name)
-                .expect(format!("Failed to retrieve the {} field", #ident_name).as_ref())
+                .map_err(|_| canyon_sql::crud::CanyonError::mapping(#ident_name, #ty_name))?
         }
     });
 
     #[cfg(feature = "mssql")]
     let init_field_values_sqlserver = fields.iter().map(|(_vis, ident, ty)| {
         let ident_name = ident.to_string();
+        let ty_name = get_field_type_as_string(ty).replace(' ', "");
 
-        if get_field_type_as_string(ty) == "String" {
-            quote! {
-                #ident: row.get::<&str, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-                    .to_string()
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<i64>" {
-            quote! {
-                #ident: row.get::<i64, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<i32>" {
-            quote! {
-                #ident: row.get::<i32, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<i16>" {
-            quote! {
-                #ident: row.get::<i16, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<f32>" {
-            quote! {
-                #ident: row.get::<f32, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<f64>" {
-            quote! {
-                #ident: row.get::<f64, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<String>" {
-            quote! {
-                #ident: row.get::<&str, &str>(#ident_name)
-                    .map( |x| x.to_owned() )
-            }
-        } else if get_field_type_as_string(ty) == "NaiveDate" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDate, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<NaiveDate>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDate, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty) == "NaiveTime" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveTime, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<NaiveTime>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveTime, &str>(#ident_name)
-            }
-        } else if get_field_type_as_string(ty) == "NaiveDateTime" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDateTime, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
-            }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<NaiveDateTime>" {
+        // Tiberius returns `None` for the NULL values, so they only are an error
+        // when the field isn't an `Option`
+        let required = |get_ty: TokenStream| {
             quote! {
-                #ident: row.get::<canyon_sql::date_time::NaiveDateTime, &str>(#ident_name)
+                row.try_get::<#get_ty, &str>(#ident_name)
+                    .ok()
+                    .flatten()
+                    .ok_or_else(|| canyon_sql::crud::CanyonError::mapping(#ident_name, #ty_name))?
             }
-        } else if get_field_type_as_string(ty) == "DateTime" {
+        };
+        let optional = |get_ty: TokenStream| {
             quote! {
-                #ident: row.get::<canyon_sql::date_time::DateTime, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
+                row.try_get::<#get_ty, &str>(#ident_name)
+                    .map_err(|_| canyon_sql::crud::CanyonError::mapping(#ident_name, #ty_name))?
             }
-        } else if get_field_type_as_string(ty).replace(' ', "") == "Option<DateTime>" {
-            quote! {
-                #ident: row.get::<canyon_sql::date_time::DateTime, &str>(#ident_name)
+        };
+
+        let value = match ty_name.as_str() {
+            "String" => {
+                let value = required(quote! { &str });
+                quote! { #value.to_string() }
             }
-        } else {
-            quote! {
-                #ident: row.get::<#ty, &str>(#ident_name)
-                    .expect(format!("Failed to retrieve the `{}` field", #ident_name).as_ref())
+            "Option<String>" => {
+                let value = optional(quote! { &str });
+                quote! { #value.map( |x| x.to_owned() ) }
             }
-        }
+            "Option<i64>" => optional(quote! { i64 }),
+            "Option<i32>" => optional(quote! { i32 }),
+            "Option<i16>" => optional(quote! { i16 }),
+            "Option<f32>" => optional(quote! { f32 }),
+            "Option<f64>" => optional(quote! { f64 }),
+            "NaiveDate" => required(quote! { canyon_sql::date_time::NaiveDate }),
+            "Option<NaiveDate>" => optional(quote! { canyon_sql::date_time::NaiveDate }),
+            "NaiveTime" => required(quote! { canyon_sql::date_time::NaiveTime }),
+            "Option<NaiveTime>" => optional(quote! { canyon_sql::date_time::NaiveTime }),
+            "NaiveDateTime" => required(quote! { canyon_sql::date_time::NaiveDateTime }),
+            "Option<NaiveDateTime>" => optional(quote! { canyon_sql::date_time::NaiveDateTime }),
+            "DateTime" => required(quote! { canyon_sql::date_time::DateTime }),
+            "Option<DateTime>" => optional(quote! { canyon_sql::date_time::DateTime }),
+            _ => required(quote! { #ty }),
+        };
+
+        quote! { #ident: #value }
     });
 
     #[cfg(feature = "mysql")]
-    let init_field_values_mysql = fields.iter().map(|(_vis, ident, _ty)| {
+    let init_field_values_mysql = fields.iter().map(|(_vis, ident, ty)| {
+        let ident_name = ident.to_string();
+        let ty_name = get_field_type_as_string(ty).replace(' ', "");
+        quote! {
+            #ident: row.get_opt(#ident_name)
+                .and_then(|value| value.ok())
+                .ok_or_else(|| canyon_sql::crud::CanyonError::mapping(#ident_name, #ty_name))?
+        }
+    });
+
+    #[cfg(feature = "sqlite")]
+    let init_field_values_sqlite = fields.iter().map(|(_vis, ident, ty)| {
         let ident_name = ident.to_string();
+        let ty_name = get_field_type_as_string(ty).replace(' ', "");
         quote! {
-            #ident: row.get(#ident_name)
-                .expect(format!("Failed to retrieve the {} field", #ident_name).as_ref())
+            #ident: row.try_get(#ident_name)
+                .map_err(|_| canyon_sql::crud::CanyonError::mapping(#ident_name, #ty_name))?
+        }
+    });
+
+    #[cfg(feature = "mock")]
+    let init_field_values_mock = fields.iter().map(|(_vis, ident, ty)| {
+        let ident_name = ident.to_string();
+        let ty_name = get_field_type_as_string(ty).replace(' ', "");
+        quote! {
+            #ident: row.try_get(#ident_name)
+                .map_err(|_| canyon_sql::crud::CanyonError::mapping(#ident_name, #ty_name))?
         }
     });
 
@@ -551,28 +566,56 @@ pub fn implement_row_mapper_for_type(input: proc_macro::TokenStream) -> proc_mac
 
     #[cfg(feature = "postgres")]
     impl_methods.extend(quote! {
-        fn deserialize_postgresql(row: &canyon_sql::db_clients::tokio_postgres::Row) -> #ty {
-            Self {
+        fn deserialize_postgresql(row: &canyon_sql::db_clients::tokio_postgres::Row)
+            -> Result<#ty, canyon_sql::crud::CanyonError>
+        {
+            Ok(Self {
                 #(#init_field_values),*
-            }
+            })
         }
     });
 
     #[cfg(feature = "mssql")]
     impl_methods.extend(quote! {
-        fn deserialize_sqlserver(row: &canyon_sql::db_clients::tiberius::Row) -> #ty {
-            Self {
+        fn deserialize_sqlserver(row: &canyon_sql::db_clients::tiberius::Row)
+            -> Result<#ty, canyon_sql::crud::CanyonError>
+        {
+            Ok(Self {
                 #(#init_field_values_sqlserver),*
-            }
+            })
         }
     });
 
     #[cfg(feature = "mysql")]
     impl_methods.extend(quote! {
-        fn deserialize_mysql(row: &canyon_sql::db_clients::mysql_async::Row) -> #ty {
-            Self {
+        fn deserialize_mysql(row: &canyon_sql::db_clients::mysql_async::Row)
+            -> Result<#ty, canyon_sql::crud::CanyonError>
+        {
+            Ok(Self {
                 #(#init_field_values_mysql),*
-            }
+            })
+        }
+    });
+
+    #[cfg(feature = "sqlite")]
+    impl_methods.extend(quote! {
+        fn deserialize_sqlite(row: &canyon_sql::crud::SqliteRow)
+            -> Result<#ty, canyon_sql::crud::CanyonError>
+        {
+            Ok(Self {
+                #(#init_field_values_sqlite),*
+            })
+        }
+    });
+
+    #[cfg(feature = "mock")]
+    impl_methods.extend(quote! {
+        fn deserialize_mock(row: &canyon_sql::mock::MockRow)
+            -> Result<#ty, canyon_sql::crud::CanyonError>
+        {
+            Ok(Self {
+                #(#init_field_values_mock),*
+            })
         }
     });
 
@@ -607,9 +650,6 @@ fn fields_with_types(fields: &Fields) -> Vec<(Visibility, Ident, Type)> {
         .collect::<Vec<_>>()
 }
 
-#[cfg(feature = "mssql")]
-use quote::ToTokens;
-#[cfg(feature = "mssql")]
 fn get_field_type_as_string(typ: &Type) -> String {
     match typ {
         Type::Array(type_) => type_.to_token_stream().to_string(),
@@ -25,7 +25,7 @@ pub fn generate_delete_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
             /// Deletes from a database entity the row that matches
             /// the current instance of a T type, returning a result
             /// indicating a possible failure querying the database.
-            async fn delete(&self) -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>> {
+            async fn delete(&self) -> Result<(), canyon_sql::crud::CanyonError> {
                 <#ty as canyon_sql::crud::Transaction<#ty>>::query(
                     format!("DELETE FROM {} WHERE {:?} = $1", #table_schema_data, #primary_key),
                     &[#pk_field_value],
@@ -39,7 +39,7 @@ pub fn generate_delete_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
             /// the current instance of a T type, returning a result
             /// indicating a possible failure querying the database with the specified datasource.
             async fn delete_datasource<'a>(&self, datasource_name: &'a str)
-                -> Result<(), Box<(dyn std::error::Error + Send + Sync + 'static)>>
+                -> Result<(), canyon_sql::crud::CanyonError>
             {
                 <#ty as canyon_sql::crud::Transaction<#ty>>::query(
                     format!("DELETE FROM {} WHERE {:?} = $1", #table_schema_data, #primary_key),
@@ -49,31 +49,54 @@ pub fn generate_delete_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
 
                 Ok(())
             }
+
+            /// Deletes from a database entity the row that matches
+            /// the current instance of a T type, returning a result
+            /// indicating a possible failure querying the database within the given transaction.
+            async fn delete_transaction(&self, transaction: &mut canyon_sql::crud::CanyonTransaction)
+                -> Result<(), canyon_sql::crud::CanyonError>
+            {
+                <#ty as canyon_sql::crud::Transaction<#ty>>::query_transaction(
+                    format!("DELETE FROM {} WHERE {:?} = $1", #table_schema_data, #primary_key),
+                    &[#pk_field_value],
+                    transaction
+                ).await?;
+
+                Ok(())
+            }
         }
     } else {
         // Delete operation over an instance isn't available without declaring a primary key.
         // The delete querybuilder variant must be used for the case when there's no pk declared
         quote! {
             async fn delete(&self)
-                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+                -> Result<(), canyon_sql::crud::CanyonError>
             {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::Unsupported,
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
                     "You can't use the 'delete' method on a \
                     CanyonEntity that does not have a #[primary_key] annotation. \
                     If you need to perform an specific search, use the Querybuilder instead."
-                ).into_inner().unwrap())
+                )))
             }
 
             async fn delete_datasource<'a>(&self, datasource_name: &'a str)
-                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+                -> Result<(), canyon_sql::crud::CanyonError>
             {
-                Err(std::io::Error::new(
-                    std::io::ErrorKind::Unsupported,
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
                     "You can't use the 'delete_datasource' method on a \
                     CanyonEntity that does not have a #[primary_key] annotation. \
                     If you need to perform an specific search, use the Querybuilder instead."
-                ).into_inner().unwrap())
+                )))
+            }
+
+            async fn delete_transaction(&self, transaction: &mut canyon_sql::crud::CanyonTransaction)
+                -> Result<(), canyon_sql::crud::CanyonError>
+            {
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'delete_transaction' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
             }
         }
     }
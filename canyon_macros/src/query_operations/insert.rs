@@ -1,6 +1,7 @@
 use proc_macro2::TokenStream;
 use quote::quote;
 
+use crate::query_operations::launch_query;
 use crate::utils::macro_tokens::MacroTokens;
 
 /// Generates the TokenStream for the _insert_result() CRUD operation
@@ -21,6 +22,7 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         quote! { &self.#ident }
     });
     let insert_values_cloned = insert_values.clone();
+    let insert_values_transaction = insert_values.clone();
 
     let primary_key = macro_data.get_primary_key_annotation();
 
@@ -34,76 +36,109 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         ._fields_with_types()
         .into_iter()
         .find(|(i, _t)| Some(i.to_string()) == primary_key);
-    let insert_transaction = if let Some(pk_data) = &pk_ident_type {
-        let pk_ident = &pk_data.0;
-        let pk_type = &pk_data.1;
-
-        quote! {
-            #remove_pk_value_from_fn_entry;
-
-            let stmt = format!(
-                "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
-                #table_schema_data,
-                #insert_columns,
-                #placeholders,
-                #primary_key
-            );
-
-            let rows = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                stmt,
-                values,
-                datasource_name
-            ).await?;
-
-           match rows {
-                #[cfg(feature = "postgres")]
-                canyon_sql::crud::CanyonRows::Postgres(mut v) => {
-                    self.#pk_ident = v
-                        .get(0)
-                        .ok_or("Failed getting the returned IDs for an insert")?
-                        .get::<&str, #pk_type>(#primary_key);
-                    Ok(())
-                },
-                #[cfg(feature = "mssql")]
-                canyon_sql::crud::CanyonRows::Tiberius(mut v) => {
-                    self.#pk_ident = v
-                        .get(0)
-                        .ok_or("Failed getting the returned IDs for a multi insert")?
-                        .get::<#pk_type, &str>(#primary_key)
-                        .ok_or("SQL Server primary key type failed to be set as value")?;
-                    Ok(())
-                },
-                #[cfg(feature = "mysql")]
-                canyon_sql::crud::CanyonRows::MySQL(mut v) => {
-                    self.#pk_ident = v
-                        .get(0)
-                        .ok_or("Failed getting the returned IDs for a multi insert")?
-                        .get::<#pk_type,usize>(0)
-                        .ok_or("MYSQL primary key type failed to be set as value")?;
-                    Ok(())
-                },
-                _ => panic!("Reached the panic match arm of insert for the DatabaseConnection type") // TODO remove when the generics will be refactored
+
+    // The body of the insert operations, which only changes in the way of launching the query
+    let insert_body = |transactional: bool| {
+        let launch = launch_query(ty, quote! { stmt }, quote! { values }, transactional);
+
+        if let Some(pk_data) = &pk_ident_type {
+            let pk_ident = &pk_data.0;
+            let pk_type = &pk_data.1;
+            let pk_type_str = quote! { #pk_type }.to_string().replace(' ', "");
+
+            quote! {
+                #remove_pk_value_from_fn_entry;
+
+                let stmt = format!(
+                    "INSERT INTO {} ({}) VALUES ({}) RETURNING {}",
+                    #table_schema_data,
+                    #insert_columns,
+                    #placeholders,
+                    #primary_key
+                );
+
+                let rows = #launch.await?;
+
+               match rows {
+                    #[cfg(feature = "postgres")]
+                    canyon_sql::crud::CanyonRows::Postgres(mut v) => {
+                        self.#pk_ident = v
+                            .get(0)
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                String::from("Failed getting the returned IDs for an insert")
+                            ))?
+                            .try_get::<&str, #pk_type>(#primary_key)
+                            .map_err(|_| canyon_sql::crud::CanyonError::mapping(#primary_key, #pk_type_str))?;
+                        Ok(())
+                    },
+                    #[cfg(feature = "mssql")]
+                    canyon_sql::crud::CanyonRows::Tiberius(mut v) => {
+                        self.#pk_ident = v
+                            .get(0)
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                String::from("Failed getting the returned IDs for an insert")
+                            ))?
+                            .try_get::<#pk_type, &str>(#primary_key)
+                            .ok()
+                            .flatten()
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::mapping(#primary_key, #pk_type_str))?;
+                        Ok(())
+                    },
+                    #[cfg(feature = "mysql")]
+                    canyon_sql::crud::CanyonRows::MySQL(mut v) => {
+                        self.#pk_ident = v
+                            .get(0)
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                String::from("Failed getting the returned IDs for an insert")
+                            ))?
+                            .get_opt::<#pk_type, usize>(0)
+                            .and_then(|pk| pk.ok())
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::mapping(#primary_key, #pk_type_str))?;
+                        Ok(())
+                    },
+                    #[cfg(feature = "sqlite")]
+                    canyon_sql::crud::CanyonRows::Sqlite(mut v) => {
+                        self.#pk_ident = v
+                            .get(0)
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                String::from("Failed getting the returned IDs for an insert")
+                            ))?
+                            .try_get::<#pk_type>(#primary_key)
+                            .map_err(|_| canyon_sql::crud::CanyonError::mapping(#primary_key, #pk_type_str))?;
+                        Ok(())
+                    },
+                    #[cfg(feature = "mock")]
+                    canyon_sql::crud::CanyonRows::Mock(mut v) => {
+                        self.#pk_ident = v
+                            .get(0)
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                String::from("Failed getting the returned IDs for an insert")
+                            ))?
+                            .try_get::<#pk_type>(#primary_key)
+                            .map_err(|_| canyon_sql::crud::CanyonError::mapping(#primary_key, #pk_type_str))?;
+                        Ok(())
+                    },
+                    _ => panic!("Reached the panic match arm of insert for the DatabaseConnection type") // TODO remove when the generics will be refactored
+                }
+            }
+        } else {
+            quote! {
+                let stmt = format!(
+                    "INSERT INTO {} ({}) VALUES ({})",
+                    #table_schema_data,
+                    #insert_columns,
+                    #placeholders,
+                    #primary_key
+                );
+
+                #launch.await?;
+
+                Ok(())
             }
-        }
-    } else {
-        quote! {
-            let stmt = format!(
-                "INSERT INTO {} ({}) VALUES ({})",
-                #table_schema_data,
-                #insert_columns,
-                #placeholders,
-                #primary_key
-            );
-
-            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                stmt,
-                values,
-                datasource_name
-            ).await?;
-
-            Ok(())
         }
     };
+    let insert_with_datasource = insert_body(false);
+    let insert_with_transaction = insert_body(true);
 
     quote! {
         /// Inserts into a database entity the current data in `self`, generating a new
@@ -145,11 +180,11 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         /// ```
         ///
         async fn insert<'a>(&mut self)
-            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            -> Result<(), canyon_sql::crud::CanyonError>
         {
             let datasource_name = "";
             let mut values: Vec<&dyn canyon_sql::crud::bounds::QueryParameter<'_>> = vec![#(#insert_values),*];
-            #insert_transaction
+            #insert_with_datasource
         }
 
         /// Inserts into a database entity the current data in `self`, generating a new
@@ -191,10 +226,20 @@ pub fn generate_insert_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         /// ```
         ///
         async fn insert_datasource<'a>(&mut self, datasource_name: &'a str)
-            -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            -> Result<(), canyon_sql::crud::CanyonError>
         {
             let mut values: Vec<&dyn canyon_sql::crud::bounds::QueryParameter<'_>> = vec![#(#insert_values_cloned),*];
-            #insert_transaction
+            #insert_with_datasource
+        }
+
+        /// Inserts into a database entity the current data in `self`, exactly as the
+        /// `insert` operation does, but within the given transaction, so the new row
+        /// will only be persisted when the transaction commits
+        async fn insert_transaction(&mut self, transaction: &mut canyon_sql::crud::CanyonTransaction)
+            -> Result<(), canyon_sql::crud::CanyonError>
+        {
+            let mut values: Vec<&dyn canyon_sql::crud::bounds::QueryParameter<'_>> = vec![#(#insert_values_transaction),*];
+            #insert_with_transaction
         }
 
     }
@@ -220,6 +265,7 @@ pub fn generate_multiple_insert_tokens(
 
     let macro_fields = fields.iter().map(|field| quote! { &instance.#field });
     let macro_fields_cloned = macro_fields.clone();
+    let macro_fields_transaction = macro_fields.clone();
 
     let pk = macro_data.get_primary_key_annotation().unwrap_or_default();
 
@@ -228,180 +274,218 @@ pub fn generate_multiple_insert_tokens(
         .into_iter()
         .find(|(i, _t)| *i == pk);
 
-    let multi_insert_transaction = if let Some(pk_data) = &pk_ident_type {
-        let pk_ident = &pk_data.0;
-        let pk_type = &pk_data.1;
-
-        quote! {
-            mapped_fields = #column_names
-                .split(", ")
-                .map( |column_name| format!("\"{}\"", column_name))
-                .collect::<Vec<String>>()
-                .join(", ");
-
-            let mut split = mapped_fields.split(", ")
-                .collect::<Vec<&str>>();
-
-            let pk_value_index = split.iter()
-                .position(|pk| *pk == format!("\"{}\"", #pk).as_str())
-                .expect("Error. No primary key found when should be there");
-            split.retain(|pk| *pk != format!("\"{}\"", #pk).as_str());
-            mapped_fields = split.join(", ").to_string();
-
-            let mut fields_placeholders = String::new();
-
-            let mut elements_counter = 0;
-            let mut values_counter = 1;
-            let values_arr_len = final_values.len();
+    // The body of the multi insert operations, which only changes in the way of launching the query
+    let multi_insert_body = |transactional: bool| {
+        let launch = launch_query(ty, quote! { stmt }, quote! { v_arr }, transactional);
+
+        if let Some(pk_data) = &pk_ident_type {
+            let pk_ident = &pk_data.0;
+            let pk_type = &pk_data.1;
+            let pk_type_str = quote! { #pk_type }.to_string().replace(' ', "");
+
+            quote! {
+                mapped_fields = #column_names
+                    .split(", ")
+                    .map( |column_name| format!("\"{}\"", column_name))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let mut split = mapped_fields.split(", ")
+                    .collect::<Vec<&str>>();
+
+                let pk_value_index = split.iter()
+                    .position(|pk| *pk == format!("\"{}\"", #pk).as_str())
+                    .expect("Error. No primary key found when should be there");
+                split.retain(|pk| *pk != format!("\"{}\"", #pk).as_str());
+                mapped_fields = split.join(", ").to_string();
+
+                let mut fields_placeholders = String::new();
+
+                let mut elements_counter = 0;
+                let mut values_counter = 1;
+                let values_arr_len = final_values.len();
+
+                for vector in final_values.iter_mut() {
+                    let mut inner_counter = 0;
+                    fields_placeholders.push('(');
+                    vector.remove(pk_value_index);
+
+                    for _value in vector.iter() {
+                        if inner_counter < vector.len() - 1 {
+                            fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string() + ","));
+                        } else {
+                            fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string()));
+                        }
+
+                        inner_counter += 1;
+                        values_counter += 1;
+                    }
 
-            for vector in final_values.iter_mut() {
-                let mut inner_counter = 0;
-                fields_placeholders.push('(');
-                vector.remove(pk_value_index);
+                    elements_counter += 1;
 
-                for _value in vector.iter() {
-                    if inner_counter < vector.len() - 1 {
-                        fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string() + ","));
+                    if elements_counter < values_arr_len {
+                        fields_placeholders.push_str("), ");
                     } else {
-                        fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string()));
+                        fields_placeholders.push(')');
                     }
-
-                    inner_counter += 1;
-                    values_counter += 1;
                 }
 
-                elements_counter += 1;
-
-                if elements_counter < values_arr_len {
-                    fields_placeholders.push_str("), ");
-                } else {
-                    fields_placeholders.push(')');
+                let stmt = format!(
+                    "INSERT INTO {} ({}) VALUES {} RETURNING {}",
+                    #table_schema_data,
+                    mapped_fields,
+                    fields_placeholders,
+                    #pk
+                );
+
+                let mut v_arr = Vec::new();
+                for arr in final_values.iter() {
+                    for value in arr {
+                        v_arr.push(*value)
+                    }
                 }
-            }
 
-            let stmt = format!(
-                "INSERT INTO {} ({}) VALUES {} RETURNING {}",
-                #table_schema_data,
-                mapped_fields,
-                fields_placeholders,
-                #pk
-            );
-
-            let mut v_arr = Vec::new();
-            for arr in final_values.iter() {
-                for value in arr {
-                    v_arr.push(*value)
+                let multi_insert_result = #launch.await?;
+
+                match multi_insert_result {
+                    #[cfg(feature="postgres")]
+                    canyon_sql::crud::CanyonRows::Postgres(mut v) => {
+                        for (idx, instance) in instances.iter_mut().enumerate() {
+                            instance.#pk_ident = v
+                                .get(idx)
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                    String::from("Failed getting the returned IDs for a multi insert")
+                                ))?
+                                .try_get::<&str, #pk_type>(#pk)
+                                .map_err(|_| canyon_sql::crud::CanyonError::mapping(#pk, #pk_type_str))?;
+                        }
+
+                        Ok(())
+                    },
+                    #[cfg(feature="mssql")]
+                    canyon_sql::crud::CanyonRows::Tiberius(mut v) => {
+                        for (idx, instance) in instances.iter_mut().enumerate() {
+                            instance.#pk_ident = v
+                                .get(idx)
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                    String::from("Failed getting the returned IDs for a multi insert")
+                                ))?
+                                .try_get::<#pk_type, &str>(#pk)
+                                .ok()
+                                .flatten()
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::mapping(#pk, #pk_type_str))?;
+                        }
+
+                        Ok(())
+                    },
+                    #[cfg(feature="mysql")]
+                    canyon_sql::crud::CanyonRows::MySQL(mut v) => {
+                        for (idx, instance) in instances.iter_mut().enumerate() {
+                            instance.#pk_ident = v
+                                .get(idx)
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                    String::from("Failed getting the returned IDs for a multi insert")
+                                ))?
+                                .get_opt::<#pk_type, usize>(0)
+                                .and_then(|pk| pk.ok())
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::mapping(#pk, #pk_type_str))?;
+                        }
+                        Ok(())
+                    },
+                    #[cfg(feature="sqlite")]
+                    canyon_sql::crud::CanyonRows::Sqlite(mut v) => {
+                        for (idx, instance) in instances.iter_mut().enumerate() {
+                            instance.#pk_ident = v
+                                .get(idx)
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                    String::from("Failed getting the returned IDs for a multi insert")
+                                ))?
+                                .try_get::<#pk_type>(#pk)
+                                .map_err(|_| canyon_sql::crud::CanyonError::mapping(#pk, #pk_type_str))?;
+                        }
+
+                        Ok(())
+                    },
+                    #[cfg(feature="mock")]
+                    canyon_sql::crud::CanyonRows::Mock(mut v) => {
+                        for (idx, instance) in instances.iter_mut().enumerate() {
+                            instance.#pk_ident = v
+                                .get(idx)
+                                .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(
+                                    String::from("Failed getting the returned IDs for a multi insert")
+                                ))?
+                                .try_get::<#pk_type>(#pk)
+                                .map_err(|_| canyon_sql::crud::CanyonError::mapping(#pk, #pk_type_str))?;
+                        }
+
+                        Ok(())
+                    },
+                    _ => panic!() // TODO remove when the generics will be refactored
                 }
             }
-
-            let multi_insert_result = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                stmt,
-                v_arr,
-                datasource_name
-            ).await?;
-
-            match multi_insert_result {
-                #[cfg(feature="postgres")]
-                canyon_sql::crud::CanyonRows::Postgres(mut v) => {
-                    for (idx, instance) in instances.iter_mut().enumerate() {
-                        instance.#pk_ident = v
-                            .get(idx)
-                            .expect("Failed getting the returned IDs for a multi insert")
-                            .get::<&str, #pk_type>(#pk);
-                    }
-
-                    Ok(())
-                },
-                #[cfg(feature="mssql")]
-                canyon_sql::crud::CanyonRows::Tiberius(mut v) => {
-                    for (idx, instance) in instances.iter_mut().enumerate() {
-                        instance.#pk_ident = v
-                            .get(idx)
-                            .expect("Failed getting the returned IDs for a multi insert")
-                            .get::<#pk_type, &str>(#pk)
-                            .expect("SQL Server primary key type failed to be set as value");
+        } else {
+            quote! {
+                mapped_fields = #column_names
+                    .split(", ")
+                    .map( |column_name| format!("\"{}\"", column_name))
+                    .collect::<Vec<String>>()
+                    .join(", ");
+
+                let mut split = mapped_fields.split(", ")
+                    .collect::<Vec<&str>>();
+
+                let mut fields_placeholders = String::new();
+
+                let mut elements_counter = 0;
+                let mut values_counter = 1;
+                let values_arr_len = final_values.len();
+
+                for vector in final_values.iter_mut() {
+                    let mut inner_counter = 0;
+                    fields_placeholders.push('(');
+
+                    for _value in vector.iter() {
+                        if inner_counter < vector.len() - 1 {
+                            fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string() + ","));
+                        } else {
+                            fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string()));
+                        }
+
+                        inner_counter += 1;
+                        values_counter += 1;
                     }
 
-                    Ok(())
-                },
-                #[cfg(feature="mysql")]
-                canyon_sql::crud::CanyonRows::MySQL(mut v) => {
-                    for (idx, instance) in instances.iter_mut().enumerate() {
-                        instance.#pk_ident = v
-                            .get(idx)
-                            .expect("Failed getting the returned IDs for a multi insert")
-                            .get::<#pk_type,usize>(0)
-                            .expect("MYSQL primary key type failed to be set as value");
-                    }
-                    Ok(())
-                },
-                _ => panic!() // TODO remove when the generics will be refactored
-            }
-        }
-    } else {
-        quote! {
-            mapped_fields = #column_names
-                .split(", ")
-                .map( |column_name| format!("\"{}\"", column_name))
-                .collect::<Vec<String>>()
-                .join(", ");
-
-            let mut split = mapped_fields.split(", ")
-                .collect::<Vec<&str>>();
-
-            let mut fields_placeholders = String::new();
-
-            let mut elements_counter = 0;
-            let mut values_counter = 1;
-            let values_arr_len = final_values.len();
+                    elements_counter += 1;
 
-            for vector in final_values.iter_mut() {
-                let mut inner_counter = 0;
-                fields_placeholders.push('(');
-
-                for _value in vector.iter() {
-                    if inner_counter < vector.len() - 1 {
-                        fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string() + ","));
+                    if elements_counter < values_arr_len {
+                        fields_placeholders.push_str("), ");
                     } else {
-                        fields_placeholders.push_str(&("$".to_owned() + &values_counter.to_string()));
+                        fields_placeholders.push(')');
                     }
-
-                    inner_counter += 1;
-                    values_counter += 1;
                 }
 
-                elements_counter += 1;
-
-                if elements_counter < values_arr_len {
-                    fields_placeholders.push_str("), ");
-                } else {
-                    fields_placeholders.push(')');
-                }
-            }
-
-            let stmt = format!(
-                "INSERT INTO {} ({}) VALUES {}",
-                #table_schema_data,
-                mapped_fields,
-                fields_placeholders
-            );
-
-            let mut v_arr = Vec::new();
-            for arr in final_values.iter() {
-                for value in arr {
-                    v_arr.push(*value)
+                let stmt = format!(
+                    "INSERT INTO {} ({}) VALUES {}",
+                    #table_schema_data,
+                    mapped_fields,
+                    fields_placeholders
+                );
+
+                let mut v_arr = Vec::new();
+                for arr in final_values.iter() {
+                    for value in arr {
+                        v_arr.push(*value)
+                    }
                 }
-            }
 
-            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                stmt,
-                v_arr,
-                datasource_name
-            ).await?;
+                #launch.await?;
 
-            Ok(())
+                Ok(())
+            }
         }
     };
+    let multi_insert_with_datasource = multi_insert_body(false);
+    let multi_insert_with_transaction = multi_insert_body(true);
 
     quote! {
         /// Inserts multiple instances of some type `T` into its related table.
@@ -438,7 +522,7 @@ pub fn generate_multiple_insert_tokens(
         /// .ok();
         /// ```
         async fn multi_insert<'a>(instances: &'a mut [&'a mut #ty]) -> (
-            Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            Result<(), canyon_sql::crud::CanyonError>
         ) {
             use canyon_sql::crud::bounds::QueryParameter;
             let datasource_name = "";
@@ -457,7 +541,7 @@ pub fn generate_multiple_insert_tokens(
 
             let mut mapped_fields: String = String::new();
 
-            #multi_insert_transaction
+            #multi_insert_with_datasource
         }
 
         /// Inserts multiple instances of some type `T` into its related table with the specified
@@ -495,7 +579,7 @@ pub fn generate_multiple_insert_tokens(
         /// .ok();
         /// ```
         async fn multi_insert_datasource<'a>(instances: &'a mut [&'a mut #ty], datasource_name: &'a str) -> (
-            Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+            Result<(), canyon_sql::crud::CanyonError>
         ) {
             use canyon_sql::crud::bounds::QueryParameter;
 
@@ -513,7 +597,35 @@ pub fn generate_multiple_insert_tokens(
 
             let mut mapped_fields: String = String::new();
 
-            #multi_insert_transaction
+            #multi_insert_with_datasource
+        }
+
+        /// Inserts multiple instances of some type `T` into its related table, exactly as
+        /// the `multi_insert` operation does, but within the given transaction, so the new
+        /// rows will only be persisted when the transaction commits
+        async fn multi_insert_transaction<'a>(
+            instances: &'a mut [&'a mut #ty],
+            transaction: &mut canyon_sql::crud::CanyonTransaction
+        ) -> (
+            Result<(), canyon_sql::crud::CanyonError>
+        ) {
+            use canyon_sql::crud::bounds::QueryParameter;
+
+            let mut final_values: Vec<Vec<&dyn QueryParameter<'_>>> = Vec::new();
+            for instance in instances.iter() {
+                let intermediate: &[&dyn QueryParameter<'_>] = &[#(#macro_fields_transaction),*];
+
+                let mut longer_lived: Vec<&dyn QueryParameter<'_>> = Vec::new();
+                for value in intermediate.into_iter() {
+                    longer_lived.push(*value)
+                }
+
+                final_values.push(longer_lived)
+            }
+
+            let mut mapped_fields: String = String::new();
+
+            #multi_insert_with_transaction
         }
     }
 }
@@ -1,4 +1,37 @@
+use proc_macro2::{Ident, TokenStream};
+use quote::quote;
+
 pub mod delete;
 pub mod insert;
 pub mod select;
 pub mod update;
+
+/// Generates the call that launches the query of a CRUD operation.
+///
+/// When `transactional`, the query runs through the `transaction` binding in scope
+/// of the generated operation. Otherwise, it runs against the datasource named by
+/// the `datasource_name` binding in scope
+pub fn launch_query(
+    ty: &Ident,
+    stmt: TokenStream,
+    params: TokenStream,
+    transactional: bool,
+) -> TokenStream {
+    if transactional {
+        quote! {
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query_transaction(
+                #stmt,
+                #params,
+                transaction
+            )
+        }
+    } else {
+        quote! {
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+                #stmt,
+                #params,
+                datasource_name
+            )
+        }
+    }
+}
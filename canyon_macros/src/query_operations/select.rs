@@ -21,13 +21,14 @@ pub fn generate_find_all_unchecked_tokens(
         /// database convention. P.ej. PostgreSQL prefers table names declared
         /// with snake_case identifiers.
         async fn find_all_unchecked<'a>() -> Vec<#ty> {
-            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                 #stmt,
                 &[],
                 ""
             ).await
             .unwrap()
             .into_results::<#ty>()
+            .unwrap()
         }
 
         /// Performs a `SELECT * FROM table_name`, where `table_name` it's
@@ -39,13 +40,14 @@ pub fn generate_find_all_unchecked_tokens(
         /// described in the configuration file, and selected with the [`&str`]
         /// passed as parameter.
         async fn find_all_unchecked_datasource<'a>(datasource_name: &'a str) -> Vec<#ty> {
-            <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                 #stmt,
                 &[],
                 datasource_name
             ).await
             .unwrap()
             .into_results::<#ty>()
+            .unwrap()
         }
     }
 }
@@ -65,16 +67,14 @@ pub fn generate_find_all_tokens(
         /// database convention. P.ej. PostgreSQL prefers table names declared
         /// with snake_case identifiers.
         async fn find_all<'a>() ->
-            Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+            Result<Vec<#ty>, canyon_sql::crud::CanyonError>
         {
-            Ok(
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    #stmt,
-                    &[],
-                    ""
-                ).await?
-                .into_results::<#ty>()
-            )
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
+                #stmt,
+                &[],
+                ""
+            ).await?
+            .into_results::<#ty>()
         }
 
         /// Performs a `SELECT * FROM table_name`, where `table_name` it's
@@ -90,16 +90,32 @@ pub fn generate_find_all_tokens(
         /// querying the database, or, if no errors happens, a Vec<T> containing
         /// the data found.
         async fn find_all_datasource<'a>(datasource_name: &'a str) ->
-            Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+            Result<Vec<#ty>, canyon_sql::crud::CanyonError>
         {
-            Ok(
-                <#ty as canyon_sql::crud::Transaction<#ty>>::query(
-                    #stmt,
-                    &[],
-                    datasource_name
-                ).await?
-                .into_results::<#ty>()
-            )
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
+                #stmt,
+                &[],
+                datasource_name
+            ).await?
+            .into_results::<#ty>()
+        }
+
+        /// Performs a `SELECT * FROM table_name`, where `table_name` it's
+        /// the name of your entity but converted to the corresponding
+        /// database convention. P.ej. PostgreSQL prefers table names declared
+        /// with snake_case identifiers.
+        ///
+        /// The query it's made within the given transaction, so it's able to
+        /// see the changes made by it that aren't committed yet.
+        async fn find_all_transaction(transaction: &mut canyon_sql::crud::CanyonTransaction) ->
+            Result<Vec<#ty>, canyon_sql::crud::CanyonError>
+        {
+            <#ty as canyon_sql::crud::Transaction<#ty>>::query_transaction(
+                #stmt,
+                &[],
+                transaction
+            ).await?
+            .into_results::<#ty>()
         }
     }
 }
@@ -147,33 +163,42 @@ pub fn generate_count_tokens(
     table_schema_data: &String,
 ) -> TokenStream {
     let ty = macro_data.ty;
-    let ty_str = &ty.to_string();
     let stmt = format!("SELECT COUNT(*) FROM {table_schema_data}");
 
     let result_handling = quote! {
         #[cfg(feature="postgres")]
-        canyon_sql::crud::CanyonRows::Postgres(mut v) => Ok(
-                v.remove(0).get::<&str, i64>("count")
-            ),
+        canyon_sql::crud::CanyonRows::Postgres(mut v) => v.remove(0)
+                .try_get::<&str, i64>("count")
+                .map_err(|_| canyon_sql::crud::CanyonError::mapping("count", "i64")),
         #[cfg(feature="mssql")]
         canyon_sql::crud::CanyonRows::Tiberius(mut v) =>
                 v.remove(0)
-                    .get::<i32, usize>(0)
+                    .try_get::<i32, usize>(0)
+                    .ok()
+                    .flatten()
                     .map(|c| c as i64)
-                    .ok_or(format!("Failure in the COUNT query for MSSQL for: {}", #ty_str).into())
-                    .into(),
+                    .ok_or_else(|| canyon_sql::crud::CanyonError::mapping("COUNT(*)", "i32")),
         #[cfg(feature="mysql")]
         canyon_sql::crud::CanyonRows::MySQL(mut v) => v.remove(0)
-                .get::<i64, usize>(0)
-                .ok_or(format!("Failure in the COUNT query for MYSQL for: {}", #ty_str).into()),
+                .get_opt::<i64, usize>(0)
+                .and_then(|c| c.ok())
+                .ok_or_else(|| canyon_sql::crud::CanyonError::mapping("COUNT(*)", "i64")),
+        #[cfg(feature="sqlite")]
+        canyon_sql::crud::CanyonRows::Sqlite(mut v) => v.remove(0)
+                .try_get_at::<i64>(0)
+                .map_err(|_| canyon_sql::crud::CanyonError::mapping("COUNT(*)", "i64")),
+        #[cfg(feature="mock")]
+        canyon_sql::crud::CanyonRows::Mock(mut v) => v.remove(0)
+                .try_get_at::<i64>(0)
+                .map_err(|_| canyon_sql::crud::CanyonError::mapping("COUNT(*)", "i64")),
             _ => panic!() // TODO remove when the generics will be refactored
     };
 
     quote! {
         /// Performs a COUNT(*) query over some table, returning a [`Result`] rather than panicking,
         /// wrapping a possible success or error coming from the database
-        async fn count() -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+        async fn count() -> Result<i64, canyon_sql::crud::CanyonError> {
+            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                 #stmt,
                 &[],
                 ""
@@ -186,8 +211,8 @@ pub fn generate_count_tokens(
 
         /// Performs a COUNT(*) query over some table, returning a [`Result`] rather than panicking,
         /// wrapping a possible success or error coming from the database with the specified datasource
-        async fn count_datasource<'a>(datasource_name: &'a str) -> Result<i64, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+        async fn count_datasource<'a>(datasource_name: &'a str) -> Result<i64, canyon_sql::crud::CanyonError> {
+            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                 #stmt,
                 &[],
                 datasource_name
@@ -197,6 +222,20 @@ pub fn generate_count_tokens(
                 #result_handling
             }
         }
+
+        /// Performs a COUNT(*) query over some table, returning a [`Result`] rather than panicking,
+        /// wrapping a possible success or error coming from the database within the given transaction
+        async fn count_transaction(transaction: &mut canyon_sql::crud::CanyonTransaction) -> Result<i64, canyon_sql::crud::CanyonError> {
+            let count = <#ty as canyon_sql::crud::Transaction<#ty>>::query_transaction(
+                #stmt,
+                &[],
+                transaction
+            ).await?;
+
+            match count {
+                #result_handling
+            }
+        }
     }
 }
 
@@ -213,30 +252,35 @@ pub fn generate_find_by_pk_tokens(
     if pk.is_empty() {
         return quote! {
             async fn find_by_pk<'a>(value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>)
-                -> Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+                -> Result<Option<#ty>, canyon_sql::crud::CanyonError>
             {
-                Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Unsupported,
-                        "You can't use the 'find_by_pk' associated function on a \
-                        CanyonEntity that does not have a #[primary_key] annotation. \
-                        If you need to perform an specific search, use the Querybuilder instead."
-                    ).into_inner().unwrap()
-                )
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'find_by_pk' associated function on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
             }
 
             async fn find_by_pk_datasource<'a>(
                 value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>,
                 datasource_name: &'a str
-            ) -> Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
-                Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Unsupported,
-                        "You can't use the 'find_by_pk_datasource' associated function on a \
-                        CanyonEntity that does not have a #[primary_key] annotation. \
-                        If you need to perform an specific search, use the Querybuilder instead."
-                    ).into_inner().unwrap()
-                )
+            ) -> Result<Option<#ty>, canyon_sql::crud::CanyonError> {
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'find_by_pk_datasource' associated function on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
+            }
+
+            async fn find_by_pk_transaction<'a>(
+                value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>,
+                transaction: &mut canyon_sql::crud::CanyonTransaction
+            ) -> Result<Option<#ty>, canyon_sql::crud::CanyonError> {
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'find_by_pk_transaction' associated function on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
             }
         };
     }
@@ -245,7 +289,7 @@ pub fn generate_find_by_pk_tokens(
         match result {
             n if n.len() == 0 => Ok(None),
             _ => Ok(
-                Some(result.into_results::<#ty>().remove(0))
+                Some(result.into_results::<#ty>()?.remove(0))
             )
         }
     };
@@ -264,9 +308,9 @@ pub fn generate_find_by_pk_tokens(
         /// and Option<T> with the data found wrapped in the Some(T) variant,
         /// or None if the value isn't found on the table.
         async fn find_by_pk<'a>(value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>) ->
-            Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+            Result<Option<#ty>, canyon_sql::crud::CanyonError>
         {
-            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                 #stmt,
                 vec![value],
                 ""
@@ -294,9 +338,9 @@ pub fn generate_find_by_pk_tokens(
         async fn find_by_pk_datasource<'a>(
             value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>,
             datasource_name: &'a str
-        ) -> Result<Option<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>> {
+        ) -> Result<Option<#ty>, canyon_sql::crud::CanyonError> {
 
-            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query(
+            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                 #stmt,
                 vec![value],
                 datasource_name
@@ -304,6 +348,30 @@ pub fn generate_find_by_pk_tokens(
 
             #result_handling
         }
+
+        /// Finds an element on the queried table that matches the
+        /// value of the field annotated with the `primary_key` attribute,
+        /// filtering by the column that it's declared as the primary
+        /// key on the database.
+        ///
+        /// The query it's made within the given transaction, so it's able to
+        /// see the changes made by it that aren't committed yet.
+        ///
+        /// This operation it's only available if the [`CanyonEntity`] contains
+        /// some field declared as primary key.
+        async fn find_by_pk_transaction<'a>(
+            value: &'a dyn canyon_sql::crud::bounds::QueryParameter<'a>,
+            transaction: &mut canyon_sql::crud::CanyonTransaction
+        ) -> Result<Option<#ty>, canyon_sql::crud::CanyonError> {
+
+            let result = <#ty as canyon_sql::crud::Transaction<#ty>>::query_transaction(
+                #stmt,
+                vec![value],
+                transaction
+            ).await?;
+
+            #result_handling
+        }
     }
 }
 
@@ -334,11 +402,11 @@ pub fn generate_find_by_foreign_key_tokens(
             );
             let quoted_method_signature: TokenStream = quote! {
                 async fn #method_name_ident(&self) ->
-                    Result<Option<#fk_ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+                    Result<Option<#fk_ty>, canyon_sql::crud::CanyonError>
             };
             let quoted_datasource_method_signature: TokenStream = quote! {
                 async fn #method_name_ident_ds<'a>(&self, datasource_name: &'a str) ->
-                    Result<Option<#fk_ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+                    Result<Option<#fk_ty>, canyon_sql::crud::CanyonError>
             };
 
             let stmt = format!(
@@ -350,7 +418,7 @@ pub fn generate_find_by_foreign_key_tokens(
                 match result {
                     n if n.len() == 0 => Ok(None),
                     _ => Ok(Some(
-                        result.into_results::<#fk_ty>().remove(0)
+                        result.into_results::<#fk_ty>()?.remove(0)
                     ))
                 }
             };
@@ -360,7 +428,7 @@ pub fn generate_find_by_foreign_key_tokens(
                 quote! {
                     /// Searches the parent entity (if exists) for this type
                     #quoted_method_signature {
-                        let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query(
+                        let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query_read(
                             #stmt,
                             &[&self.#field_ident as &dyn canyon_sql::crud::bounds::QueryParameter<'_>],
                             ""
@@ -376,7 +444,7 @@ pub fn generate_find_by_foreign_key_tokens(
                 quote! {
                     /// Searches the parent entity (if exists) for this type with the specified datasource
                     #quoted_datasource_method_signature {
-                        let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query(
+                        let result = <#fk_ty as canyon_sql::crud::Transaction<#fk_ty>>::query_read(
                             #stmt,
                             &[&self.#field_ident as &dyn canyon_sql::crud::bounds::QueryParameter<'_>],
                             datasource_name
@@ -417,12 +485,12 @@ pub fn generate_find_by_reverse_foreign_key_tokens(
             );
             let quoted_method_signature: TokenStream = quote! {
                 async fn #method_name_ident<'a, F: canyon_sql::crud::bounds::ForeignKeyable<F> + Sync + Send>(value: &F) ->
-                    Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+                    Result<Vec<#ty>, canyon_sql::crud::CanyonError>
             };
             let quoted_datasource_method_signature: TokenStream = quote! {
                 async fn #method_name_ident_ds<'a, F: canyon_sql::crud::bounds::ForeignKeyable<F> + Sync + Send>
                     (value: &F, datasource_name: &'a str) ->
-                    Result<Vec<#ty>, Box<(dyn std::error::Error + Send + Sync + 'static)>>
+                    Result<Vec<#ty>, canyon_sql::crud::CanyonError>
             };
 
             let f_ident = field_ident.to_string();
@@ -435,9 +503,9 @@ pub fn generate_find_by_reverse_foreign_key_tokens(
                     #quoted_method_signature
                     {
                         let lookage_value = value.get_fk_column(#column)
-                            .expect(format!(
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(format!(
                                 "Column: {:?} not found in type: {:?}", #column, #table
-                            ).as_str());
+                            )))?;
 
                         let stmt = format!(
                             "SELECT * FROM {} WHERE {} = $1",
@@ -445,11 +513,11 @@ pub fn generate_find_by_reverse_foreign_key_tokens(
                             format!("\"{}\"", #f_ident).as_str()
                         );
 
-                        Ok(<#ty as canyon_sql::crud::Transaction<#ty>>::query(
+<#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                             stmt,
                             &[lookage_value],
                             ""
-                        ).await?.into_results::<#ty>())
+                        ).await?.into_results::<#ty>()
                     }
                 },
             ));
@@ -463,9 +531,9 @@ pub fn generate_find_by_reverse_foreign_key_tokens(
                     #quoted_datasource_method_signature
                     {
                         let lookage_value = value.get_fk_column(#column)
-                            .expect(format!(
+                            .ok_or_else(|| canyon_sql::crud::CanyonError::NotFound(format!(
                                 "Column: {:?} not found in type: {:?}", #column, #table
-                            ).as_str());
+                            )))?;
 
                         let stmt = format!(
                             "SELECT * FROM {} WHERE {} = $1",
@@ -473,11 +541,11 @@ pub fn generate_find_by_reverse_foreign_key_tokens(
                             format!("\"{}\"", #f_ident).as_str()
                         );
 
-                        Ok(<#ty as canyon_sql::crud::Transaction<#ty>>::query(
+<#ty as canyon_sql::crud::Transaction<#ty>>::query_read(
                             stmt,
                             &[lookage_value],
                             datasource_name
-                        ).await?.into_results::<#ty>())
+                        ).await?.into_results::<#ty>()
                     }
                 },
             ));
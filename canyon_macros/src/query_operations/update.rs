@@ -24,6 +24,7 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         quote! { &self.#ident }
     });
     let update_values_cloned = update_values.clone();
+    let update_values_transaction = update_values.clone();
 
     if let Some(primary_key) = macro_data.get_primary_key_annotation() {
         let pk_index = macro_data
@@ -34,7 +35,7 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
             /// Updates a database record that matches
             /// the current instance of a T type, returning a result
             /// indicating a possible failure querying the database.
-            async fn update(&self) -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>> {
+            async fn update(&self) -> Result<(), canyon_sql::crud::CanyonError> {
                 let stmt = format!(
                     "UPDATE {} SET {} WHERE {} = ${:?}",
                     #table_schema_data, #str_columns_values, #primary_key, #pk_index + 1
@@ -54,7 +55,7 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
             /// indicating a possible failure querying the database with the
             /// specified datasource
             async fn update_datasource<'a>(&self, datasource_name: &'a str)
-                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+                -> Result<(), canyon_sql::crud::CanyonError>
             {
                 let stmt = format!(
                     "UPDATE {} SET {} WHERE {} = ${:?}",
@@ -68,6 +69,27 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
 
                 Ok(())
             }
+
+
+            /// Updates a database record that matches
+            /// the current instance of a T type, returning a result
+            /// indicating a possible failure querying the database within
+            /// the given transaction
+            async fn update_transaction(&self, transaction: &mut canyon_sql::crud::CanyonTransaction)
+                -> Result<(), canyon_sql::crud::CanyonError>
+            {
+                let stmt = format!(
+                    "UPDATE {} SET {} WHERE {} = ${:?}",
+                    #table_schema_data, #str_columns_values, #primary_key, #pk_index + 1
+                );
+                let update_values: &[&dyn canyon_sql::crud::bounds::QueryParameter<'_>] = &[#(#update_values_transaction),*];
+
+                <#ty as canyon_sql::crud::Transaction<#ty>>::query_transaction(
+                    stmt, update_values, transaction
+                ).await?;
+
+                Ok(())
+            }
         }
     } else {
         // If there's no primary key, update method over self won't be available.
@@ -76,29 +98,33 @@ pub fn generate_update_tokens(macro_data: &MacroTokens, table_schema_data: &Stri
         // TODO Returning an error should be a provisional way of doing this
         quote! {
             async fn update(&self)
-                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+                -> Result<(), canyon_sql::crud::CanyonError>
             {
-                Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Unsupported,
-                        "You can't use the 'update' method on a \
-                        CanyonEntity that does not have a #[primary_key] annotation. \
-                        If you need to perform an specific search, use the Querybuilder instead."
-                    ).into_inner().unwrap()
-                )
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'update' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
             }
 
             async fn update_datasource<'a>(&self, datasource_name: &'a str)
-                -> Result<(), Box<dyn std::error::Error + Sync + std::marker::Send>>
+                -> Result<(), canyon_sql::crud::CanyonError>
+            {
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'update_datasource' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
+            }
+
+            async fn update_transaction(&self, transaction: &mut canyon_sql::crud::CanyonTransaction)
+                -> Result<(), canyon_sql::crud::CanyonError>
             {
-                Err(
-                    std::io::Error::new(
-                        std::io::ErrorKind::Unsupported,
-                        "You can't use the 'update_datasource' method on a \
-                        CanyonEntity that does not have a #[primary_key] annotation. \
-                        If you need to perform an specific search, use the Querybuilder instead."
-                    ).into_inner().unwrap()
-                )
+                Err(canyon_sql::crud::CanyonError::Unsupported(String::from(
+                    "You can't use the 'update_transaction' method on a \
+                    CanyonEntity that does not have a #[primary_key] annotation. \
+                    If you need to perform an specific search, use the Querybuilder instead."
+                )))
             }
         }
     }
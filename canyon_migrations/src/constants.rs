@@ -90,6 +90,44 @@ pub mod mssql_queries {
             WHERE gi.TABLE_SCHEMA = 'dbo'";
 }
 
+#[cfg(feature = "sqlite")]
+pub mod sqlite_queries {
+    pub static CANYON_MEMORY_TABLE: &str = "CREATE TABLE IF NOT EXISTS canyon_memory (
+            id INTEGER PRIMARY KEY AUTOINCREMENT,
+            filepath TEXT NOT NULL,
+            struct_name TEXT NOT NULL,
+            declared_table_name TEXT NOT NULL
+        )";
+
+    /// SQLite has no information schema, so the same columns are built from the
+    /// `table_info` and `foreign_key_list` pragmas of every table. The constraints
+    /// are named after the ones generated by the migrations, because SQLite
+    /// doesn't keep their names
+    pub static FETCH_PUBLIC_SCHEMA: &str =
+        "SELECT
+            m.name AS table_name,
+            p.name AS column_name,
+            lower(p.type) AS data_type,
+            NULL AS character_maximum_length,
+            CASE WHEN p.\"notnull\" = 0 AND p.pk = 0 THEN 'YES' ELSE 'NO' END AS is_nullable,
+            p.dflt_value AS column_default,
+            CASE WHEN fk.\"from\" IS NOT NULL
+                THEN 'FOREIGN KEY (' || fk.\"from\" || ') REFERENCES ' || fk.\"table\" || '(' || fk.\"to\" || ')'
+                ELSE NULL END AS foreign_key_info,
+            CASE WHEN fk.\"from\" IS NOT NULL
+                THEN m.name || '_' || fk.\"from\" || '_fkey' ELSE NULL END AS foreign_key_name,
+            CASE WHEN p.pk > 0 THEN 'PRIMARY KEY (' || p.name || ')' ELSE NULL END AS primary_key_info,
+            CASE WHEN p.pk > 0 THEN m.name || '_pkey' ELSE NULL END AS primary_key_name,
+            CASE WHEN p.pk > 0 AND upper(m.sql) LIKE '%AUTOINCREMENT%' THEN 'YES' ELSE 'NO' END AS is_identity,
+            NULL AS identity_generation
+        FROM
+            sqlite_master AS m
+        JOIN pragma_table_info(m.name) AS p
+        LEFT JOIN pragma_foreign_key_list(m.name) AS fk ON fk.\"from\" = p.name
+        WHERE
+            m.type = 'table' AND m.name NOT LIKE 'sqlite_%';";
+}
+
 /// Constant string values that holds regex patterns
 pub mod regex_patterns {
     pub const EXTRACT_RUST_OPT_REGEX: &str = r"[Oo][Pp][Tt][Ii][Oo][Nn]<(?P<rust_type>[\w<>]+)>";
@@ -169,6 +207,15 @@ pub mod sqlserver_type {
     pub const DATETIME: &str = "DATETIME2";
 }
 
+/// SQLite only has a few storage classes, and the declared type of a column just sets
+/// which one is preferred. The dates and times are stored as ISO-8601 text
+#[cfg(feature = "sqlite")]
+pub mod sqlite_type {
+    pub const INTEGER: &str = "INTEGER";
+    pub const TEXT: &str = "TEXT";
+    pub const BOOLEAN: &str = "BOOLEAN";
+}
+
 pub mod mocked_data {
     use crate::migrations::information_schema::{ColumnMetadata, TableMetadata};
     use canyon_connection::lazy_static::lazy_static;
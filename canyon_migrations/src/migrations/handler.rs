@@ -1,5 +1,7 @@
 use canyon_connection::{datasources::Migrations as MigrationsStatus, DATASOURCES};
 use canyon_crud::rows::CanyonRows;
+#[cfg(feature = "sqlite")]
+use canyon_crud::rows::SqliteRow;
 use canyon_entities::CANYON_REGISTER_ENTITIES;
 use partialdebug::placeholder::PartialDebug;
 
@@ -48,7 +50,13 @@ impl Migrations {
             let mut migrations_processor = MigrationsProcessor::default();
 
             let canyon_entities = CANYON_REGISTER_ENTITIES.lock().unwrap().to_vec();
-            let canyon_memory = CanyonMemory::remember(datasource, &canyon_entities).await;
+            let canyon_memory = match CanyonMemory::remember(datasource, &canyon_entities).await {
+                Ok(canyon_memory) => canyon_memory,
+                Err(error) => {
+                    println!("Skipped datasource: {:?} ({error})", datasource.name);
+                    continue;
+                }
+            };
 
             // Tracked entities that must be migrated whenever Canyon starts
             let schema_status =
@@ -96,6 +104,8 @@ impl Migrations {
             DatabaseType::SqlServer => constants::mssql_queries::FETCH_PUBLIC_SCHEMA,
             #[cfg(feature = "mysql")]
             DatabaseType::MySQL => todo!("Not implemented fetch database in mysql"),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => constants::sqlite_queries::FETCH_PUBLIC_SCHEMA,
         };
 
         Self::query(query, [], datasource_name)
@@ -116,6 +126,8 @@ impl Migrations {
             CanyonRows::Postgres(v) => Self::process_tp_rows(v, db_type),
             #[cfg(feature = "mssql")]
             CanyonRows::Tiberius(v) => Self::process_tib_rows(v, db_type),
+            #[cfg(feature = "sqlite")]
+            CanyonRows::Sqlite(v) => Self::process_sqlite_rows(v, db_type),
             _ => panic!(),
         }
     }
@@ -269,6 +281,39 @@ impl Migrations {
 
         schema_info
     }
+
+    #[cfg(feature = "sqlite")]
+    fn process_sqlite_rows(
+        db_results: Vec<SqliteRow>,
+        db_type: DatabaseType,
+    ) -> Vec<TableMetadata> {
+        let mut schema_info: Vec<TableMetadata> = Vec::new();
+        for res_row in db_results.iter() {
+            let unique_table = schema_info
+                .iter_mut()
+                .find(|table| check_for_table_name(table, db_type, res_row as &dyn Row));
+            match unique_table {
+                Some(table) => {
+                    /* If a table entity it's already present on the collection, we add it
+                    the founded columns related to the table */
+                    Self::get_columns_metadata(res_row as &dyn Row, table);
+                }
+                None => {
+                    /* If there's no table for a given "table_name" property on the
+                    collection yet, we must create a new instance and attach it
+                    the founded columns data in this iteration */
+                    let mut new_table = TableMetadata {
+                        table_name: get_table_name_from_sqlite_row(res_row),
+                        columns: Vec::new(),
+                    };
+                    Self::get_columns_metadata(res_row as &dyn Row, &mut new_table);
+                    schema_info.push(new_table);
+                }
+            };
+        }
+
+        schema_info
+    }
 }
 
 #[cfg(feature = "postgres")]
@@ -283,6 +328,11 @@ fn get_table_name_from_tib_row(res_row: &tiberius::Row) -> String {
         .to_string()
 }
 
+#[cfg(feature = "sqlite")]
+fn get_table_name_from_sqlite_row(res_row: &SqliteRow) -> String {
+    res_row.try_get::<String>("table_name").unwrap_or_default()
+}
+
 fn check_for_table_name(
     table: &&mut TableMetadata,
     db_type: DatabaseType,
@@ -295,5 +345,7 @@ fn check_for_table_name(
         DatabaseType::SqlServer => table.table_name == res_row.get_mssql::<&str>("table_name"),
         #[cfg(feature = "mysql")]
         DatabaseType::MySQL => todo!(),
+        #[cfg(feature = "sqlite")]
+        DatabaseType::Sqlite => table.table_name == res_row.get_sqlite::<String>("table_name"),
     }
 }
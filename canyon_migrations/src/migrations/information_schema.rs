@@ -1,3 +1,5 @@
+#[cfg(feature = "sqlite")]
+use canyon_connection::rusqlite::types::Type as SQLITE_TY;
 #[cfg(feature = "mssql")]
 use canyon_connection::tiberius::ColumnType as TIB_TY;
 #[cfg(feature = "postgres")]
@@ -69,6 +71,12 @@ impl ColumnMetadataTypeValue {
             },
             #[cfg(feature = "mysql")]
             ColumnType::MySQL(_) => todo!(),
+            #[cfg(feature = "sqlite")]
+            ColumnType::Sqlite(v) => match v {
+                SQLITE_TY::Text => Self::StringValue(row.get_sqlite_opt::<String>(col.name())),
+                SQLITE_TY::Integer => Self::IntValue(row.get_sqlite_opt::<i32>(col.name())),
+                _ => Self::NoneValue,
+            },
         }
     }
 }
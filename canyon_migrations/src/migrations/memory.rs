@@ -1,5 +1,7 @@
 use crate::constants;
-use canyon_crud::{crud::Transaction, DatabaseType, DatasourceConfig};
+use canyon_crud::{
+    crud::Transaction, error::CanyonError, rows::CanyonRows, DatabaseType, DatasourceConfig,
+};
 use regex::Regex;
 use std::collections::HashMap;
 use std::fs;
@@ -56,12 +58,13 @@ impl Transaction<Self> for CanyonMemory {}
 
 impl CanyonMemory {
     /// Queries the database to retrieve internal data about the structures
-    /// tracked by `CanyonSQL`
+    /// tracked by `CanyonSQL`. Fails with a [`CanyonError::Unsupported`] for the
+    /// datasources whose rows of the memory can't be read
     #[allow(clippy::nonminimal_bool)]
     pub async fn remember(
         datasource: &DatasourceConfig,
         canyon_entities: &[CanyonRegisterEntity<'_>],
-    ) -> Self {
+    ) -> Result<Self, CanyonError> {
         // Creates the memory table if not exists
         Self::create_memory(&datasource.name, &datasource.get_db_type()).await;
 
@@ -72,37 +75,57 @@ impl CanyonMemory {
 
         // Manually maps the results
         let mut db_rows = Vec::new();
-        #[cfg(feature = "postgres")]
-        {
-            let mem_results: &Vec<tokio_postgres::Row> = res.get_postgres_rows();
-            for row in mem_results {
-                let db_row = CanyonMemoryRow {
-                    id: row.get::<&str, i32>("id"),
-                    filepath: row.get::<&str, String>("filepath"),
-                    struct_name: row.get::<&str, String>("struct_name").to_owned(),
-                    declared_table_name: row.get::<&str, String>("declared_table_name").to_owned(),
-                };
-                db_rows.push(db_row);
+        match res {
+            #[cfg(feature = "postgres")]
+            CanyonRows::Postgres(mem_results) => {
+                for row in mem_results {
+                    let db_row = CanyonMemoryRow {
+                        id: row.get::<&str, i32>("id"),
+                        filepath: row.get::<&str, String>("filepath"),
+                        struct_name: row.get::<&str, String>("struct_name").to_owned(),
+                        declared_table_name: row
+                            .get::<&str, String>("declared_table_name")
+                            .to_owned(),
+                    };
+                    db_rows.push(db_row);
+                }
             }
-        }
-        #[cfg(feature = "mssql")]
-        {
-            let mem_results: &Vec<tiberius::Row> = res.get_tiberius_rows();
-            for row in mem_results {
-                let db_row = CanyonMemoryRow {
-                    id: row.get::<i32, &str>("id").unwrap(),
-                    filepath: row.get::<&str, &str>("filepath").unwrap().to_string(),
-                    struct_name: row.get::<&str, &str>("struct_name").unwrap().to_string(),
-                    declared_table_name: row
-                        .get::<&str, &str>("declared_table_name")
-                        .unwrap()
-                        .to_string(),
-                };
-                db_rows.push(db_row);
+            #[cfg(feature = "mssql")]
+            CanyonRows::Tiberius(mem_results) => {
+                for row in mem_results {
+                    let db_row = CanyonMemoryRow {
+                        id: row.get::<i32, &str>("id").unwrap(),
+                        filepath: row.get::<&str, &str>("filepath").unwrap().to_string(),
+                        struct_name: row.get::<&str, &str>("struct_name").unwrap().to_string(),
+                        declared_table_name: row
+                            .get::<&str, &str>("declared_table_name")
+                            .unwrap()
+                            .to_string(),
+                    };
+                    db_rows.push(db_row);
+                }
+            }
+            #[cfg(feature = "sqlite")]
+            CanyonRows::Sqlite(mem_results) => {
+                for row in mem_results {
+                    let db_row = CanyonMemoryRow {
+                        id: row.try_get::<i32>("id").unwrap(),
+                        filepath: row.try_get::<String>("filepath").unwrap(),
+                        struct_name: row.try_get::<String>("struct_name").unwrap(),
+                        declared_table_name: row.try_get::<String>("declared_table_name").unwrap(),
+                    };
+                    db_rows.push(db_row);
+                }
+            }
+            _ => {
+                return Err(CanyonError::Unsupported(format!(
+                    "The Canyon memory isn't available for the datasource: {}",
+                    datasource.name
+                )))
             }
         }
 
-        Self::populate_memory(datasource, canyon_entities, db_rows).await
+        Ok(Self::populate_memory(datasource, canyon_entities, db_rows).await)
     }
 
     async fn populate_memory(
@@ -248,6 +271,8 @@ impl CanyonMemory {
             DatabaseType::SqlServer => constants::mssql_queries::CANYON_MEMORY_TABLE,
             #[cfg(feature = "mysql")]
             DatabaseType::MySQL => todo!("Memory table in mysql not implemented"),
+            #[cfg(feature = "sqlite")]
+            DatabaseType::Sqlite => constants::sqlite_queries::CANYON_MEMORY_TABLE,
         };
 
         Self::query(query, [], datasource_name)
@@ -15,6 +15,8 @@ use super::information_schema::{ColumnMetadata, TableMetadata};
 use super::memory::CanyonMemory;
 #[cfg(feature = "postgres")]
 use crate::migrations::transforms::{to_postgres_alter_syntax, to_postgres_syntax};
+#[cfg(feature = "sqlite")]
+use crate::migrations::transforms::{to_sqlite_alter_syntax, to_sqlite_syntax};
 #[cfg(feature = "mssql")]
 use crate::migrations::transforms::{to_sqlserver_alter_syntax, to_sqlserver_syntax};
 use canyon_entities::register_types::{CanyonRegisterEntity, CanyonRegisterEntityField};
@@ -678,6 +680,13 @@ impl MigrationsHelper {
                     == current_column_metadata.datatype;
             }
         }
+        #[cfg(feature = "sqlite")]
+        {
+            if db_type == DatabaseType::Sqlite {
+                return to_sqlite_alter_syntax(canyon_register_entity_field).to_lowercase()
+                    == current_column_metadata.datatype;
+            }
+        }
 
         false
     }
@@ -776,10 +785,11 @@ impl DatabaseOperation for TableOperation {
     async fn generate_sql(&self, datasource: &DatasourceConfig) {
         let db_type = datasource.get_db_type();
 
-        let stmt = match self {
+        let stmt: String = match self {
             TableOperation::CreateTable(table_name, table_fields) => {
                 match db_type {
-                    #[cfg(feature = "postgres")] DatabaseType::PostgreSql => {
+                    #[cfg(feature = "postgres")]
+                    DatabaseType::PostgreSql => {
                         format!(
                             "CREATE TABLE \"{table_name}\" ({});",
                             table_fields
@@ -793,49 +803,70 @@ impl DatabaseOperation for TableOperation {
                                 .join(", ")
                         )
                     }
-                    #[cfg(feature = "mssql")] DatabaseType::SqlServer => {
+                    #[cfg(feature = "mssql")]
+                    DatabaseType::SqlServer => format!(
+                        "CREATE TABLE {:?} ({:?});",
+                        table_name,
+                        table_fields
+                            .iter()
+                            .map(|entity_field| format!(
+                                "{} {}",
+                                entity_field.field_name,
+                                to_sqlserver_syntax(entity_field)
+                            ))
+                            .collect::<Vec<String>>()
+                            .join(", ")
+                    )
+                    .replace('"', ""),
+                    #[cfg(feature = "mysql")]
+                    DatabaseType::MySQL => todo!(),
+                    // SQLite can't add constraints to a table that already exists, so the
+                    // primary and foreign keys are declared along with the table
+                    #[cfg(feature = "sqlite")]
+                    DatabaseType::Sqlite => {
                         format!(
-                            "CREATE TABLE {:?} ({:?});",
-                            table_name,
+                            "CREATE TABLE \"{table_name}\" ({});",
                             table_fields
                                 .iter()
-                                .map(|entity_field| format!(
-                                    "{} {}",
-                                    entity_field.field_name,
-                                    to_sqlserver_syntax(entity_field)
-                                ))
+                                .map(sqlite_column_definition)
                                 .collect::<Vec<String>>()
                                 .join(", ")
                         )
-                            .replace('"', "")
-                    },
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    }
                 }
             }
 
             TableOperation::AlterTableName(old_table_name, new_table_name) => {
                 match db_type {
-                    #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
-                        format!("ALTER TABLE {old_table_name} RENAME TO {new_table_name};"),
-                    #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
-                        /*
-                            Notes: Brackets around `old_table_name`, p.e.
-                                exec sp_rename ['league'], 'leagues'  // NOT VALID!
-                            is only allowed for compound names split by a dot.
-                                exec sp_rename ['random.league'], 'leagues'  // OK
-
-                            CARE! This doesn't mean that we are including the schema.
-                                exec sp_rename ['dbo.random.league'], 'leagues' // OK
-                                exec sp_rename 'dbo.league', 'leagues' // OK - Schema doesn't need brackets
-
-                            Due to the automatic mapped name from Rust to DB and vice-versa, this won't
-                            be an allowed behaviour for now, only with the table_name parameter on the
-                            CanyonEntity annotation.
-                        */
-                        format!("exec sp_rename '{old_table_name}', '{new_table_name}';"),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "postgres")]
+                    DatabaseType::PostgreSql => {
+                        format!("ALTER TABLE {old_table_name} RENAME TO {new_table_name};")
+                    }
+                    #[cfg(feature = "mssql")]
+                    DatabaseType::SqlServer =>
+                    /*
+                        Notes: Brackets around `old_table_name`, p.e.
+                            exec sp_rename ['league'], 'leagues'  // NOT VALID!
+                        is only allowed for compound names split by a dot.
+                            exec sp_rename ['random.league'], 'leagues'  // OK
+
+                        CARE! This doesn't mean that we are including the schema.
+                            exec sp_rename ['dbo.random.league'], 'leagues' // OK
+                            exec sp_rename 'dbo.league', 'leagues' // OK - Schema doesn't need brackets
+
+                        Due to the automatic mapped name from Rust to DB and vice-versa, this won't
+                        be an allowed behaviour for now, only with the table_name parameter on the
+                        CanyonEntity annotation.
+                    */
+                    {
+                        format!("exec sp_rename '{old_table_name}', '{new_table_name}';")
+                    }
+                    #[cfg(feature = "mysql")]
+                    DatabaseType::MySQL => todo!(),
+                    #[cfg(feature = "sqlite")]
+                    DatabaseType::Sqlite => {
+                        format!("ALTER TABLE \"{old_table_name}\" RENAME TO \"{new_table_name}\";")
+                    }
                 }
             }
 
@@ -854,51 +885,74 @@ impl DatabaseOperation for TableOperation {
                         ),
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!(),
+                    // Declared along with the table when it's created
+                    #[cfg(feature = "sqlite")] DatabaseType::Sqlite =>
+                        sqlite_requires_rebuild("add a foreign key to", _table_name),
                 }
             }
 
             TableOperation::DeleteTableForeignKey(_table_with_foreign_key, _constraint_name) => {
                 match db_type {
-                    #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
-                        format!(
-                            "ALTER TABLE {_table_with_foreign_key} DROP CONSTRAINT {_constraint_name};",
-                        ),
-                    #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
-                        todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "postgres")]
+                    DatabaseType::PostgreSql => format!(
+                        "ALTER TABLE {_table_with_foreign_key} DROP CONSTRAINT {_constraint_name};",
+                    ),
+                    #[cfg(feature = "mssql")]
+                    DatabaseType::SqlServer => todo!(
+                        "[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"
+                    ),
+                    #[cfg(feature = "mysql")]
+                    DatabaseType::MySQL => todo!(),
+                    #[cfg(feature = "sqlite")]
+                    DatabaseType::Sqlite => {
+                        sqlite_requires_rebuild("drop a foreign key of", _table_with_foreign_key)
+                    }
                 }
             }
 
             TableOperation::AddTablePrimaryKey(_table_name, _entity_field) => {
                 match db_type {
-                    #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
-                        format!(
-                            "ALTER TABLE \"{_table_name}\" ADD PRIMARY KEY (\"{}\");",
-                            _entity_field.field_name
-                        ),
-                    #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
-                        todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "postgres")]
+                    DatabaseType::PostgreSql => format!(
+                        "ALTER TABLE \"{_table_name}\" ADD PRIMARY KEY (\"{}\");",
+                        _entity_field.field_name
+                    ),
+                    #[cfg(feature = "mssql")]
+                    DatabaseType::SqlServer => todo!(
+                        "[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"
+                    ),
+                    #[cfg(feature = "mysql")]
+                    DatabaseType::MySQL => todo!(),
+                    // Declared along with the table when it's created
+                    #[cfg(feature = "sqlite")]
+                    DatabaseType::Sqlite => {
+                        sqlite_requires_rebuild("add a primary key to", _table_name)
+                    }
                 }
             }
 
-            TableOperation::DeleteTablePrimaryKey(table_name, primary_key_name) => {
-                match db_type {
-                    #[cfg(feature = "postgres")] DatabaseType::PostgreSql =>
-                        format!("ALTER TABLE {table_name} DROP CONSTRAINT {primary_key_name} CASCADE;"),
-                    #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
-                        format!("ALTER TABLE {table_name} DROP CONSTRAINT {primary_key_name} CASCADE;"),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+            TableOperation::DeleteTablePrimaryKey(table_name, primary_key_name) => match db_type {
+                #[cfg(feature = "postgres")]
+                DatabaseType::PostgreSql => {
+                    format!("ALTER TABLE {table_name} DROP CONSTRAINT {primary_key_name} CASCADE;")
                 }
-            }
+                #[cfg(feature = "mssql")]
+                DatabaseType::SqlServer => {
+                    format!("ALTER TABLE {table_name} DROP CONSTRAINT {primary_key_name} CASCADE;")
+                }
+                #[cfg(feature = "mysql")]
+                DatabaseType::MySQL => todo!(),
+                #[cfg(feature = "sqlite")]
+                DatabaseType::Sqlite => {
+                    sqlite_requires_rebuild("drop the primary key of", table_name)
+                }
+            },
         };
 
-        save_migrations_query_to_execute(stmt, &datasource.name);
+        if !stmt.is_empty() {
+            save_migrations_query_to_execute(stmt, &datasource.name);
+        }
     }
 }
 
@@ -946,8 +1000,14 @@ impl DatabaseOperation for ColumnOperation {
                             entity_field.field_name,
                             to_sqlserver_syntax(entity_field)
                         ),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!(),
+                    #[cfg(feature = "sqlite")] DatabaseType::Sqlite =>
+                        format!(
+                            "ALTER TABLE \"{}\" ADD COLUMN \"{}\" {};",
+                            table_name,
+                            entity_field.field_name,
+                            to_sqlite_syntax(entity_field)
+                        ),
                 }
             ColumnOperation::DeleteColumn(table_name, column_name) => {
                 // TODO Check if operation for SQL server is different
@@ -962,9 +1022,9 @@ impl DatabaseOperation for ColumnOperation {
                         ),
                     #[cfg(feature = "mssql")] DatabaseType::SqlServer =>
                         todo!("[MS-SQL -> Operation still won't supported by Canyon for Sql Server]"),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
-
+                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!(),
+                    #[cfg(feature = "sqlite")] DatabaseType::Sqlite =>
+                        sqlite_requires_rebuild("change the type of a column of", _table_name),
                 }
             ColumnOperation::AlterColumnDropNotNull(table_name, entity_field) =>
                 match db_type {
@@ -975,8 +1035,9 @@ impl DatabaseOperation for ColumnOperation {
                             "ALTER TABLE \"{table_name}\" ALTER COLUMN {} {} NULL",
                             entity_field.field_name, to_sqlserver_alter_syntax(entity_field)
                         ),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!(),
+                    #[cfg(feature = "sqlite")] DatabaseType::Sqlite =>
+                        sqlite_requires_rebuild("drop the NOT NULL of a column of", table_name),
                 }
             #[cfg(feature = "mssql")] ColumnOperation::DropNotNullBeforeDropColumn(table_name, column_name, column_datatype) =>
                 format!(
@@ -1003,8 +1064,9 @@ impl DatabaseOperation for ColumnOperation {
                         entity_field.field_name,
                         to_sqlserver_alter_syntax(entity_field)
                     ),
-                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!()
-
+                    #[cfg(feature = "mysql")] DatabaseType::MySQL => todo!(),
+                    #[cfg(feature = "sqlite")] DatabaseType::Sqlite =>
+                        sqlite_requires_rebuild("set the NOT NULL of a column of", table_name),
                 }
             }
 
@@ -1017,10 +1079,58 @@ impl DatabaseOperation for ColumnOperation {
             ),
         };
 
-        save_migrations_query_to_execute(stmt, &datasource.name);
+        if !stmt.is_empty() {
+            save_migrations_query_to_execute(stmt, &datasource.name);
+        }
     }
 }
 
+/// The definition of a column in the `CREATE TABLE` statements of SQLite, including
+/// the constraints that the field is annotated with
+#[cfg(feature = "sqlite")]
+fn sqlite_column_definition(entity_field: &CanyonRegisterEntityField) -> String {
+    let mut definition = format!(
+        "\"{}\" {}",
+        entity_field.field_name,
+        to_sqlite_syntax(entity_field)
+    );
+
+    if entity_field
+        .annotations
+        .iter()
+        .any(|anno| anno.starts_with("Annotation: PrimaryKey"))
+    {
+        definition.push_str(" PRIMARY KEY");
+        if entity_field.is_autoincremental() {
+            definition.push_str(" AUTOINCREMENT");
+        }
+    }
+    if entity_field
+        .annotations
+        .iter()
+        .any(|anno| anno.starts_with("Annotation: ForeignKey"))
+    {
+        let (table_to_reference, column_to_reference) =
+            MigrationsHelper::extract_foreign_key_annotation(&entity_field.annotations);
+        definition.push_str(&format!(
+            " REFERENCES \"{table_to_reference}\" (\"{column_to_reference}\")"
+        ));
+    }
+
+    definition
+}
+
+/// SQLite only allows to rename a table, and to add, rename or drop its columns. Any other
+/// change of an existing table requires to create it again and copy its rows, which Canyon
+/// doesn't do yet, so the change is reported and skipped
+#[cfg(feature = "sqlite")]
+fn sqlite_requires_rebuild(operation: &str, table_name: &str) -> String {
+    println!(
+        "\t[SKIPPED] - SQLite can't {operation} the existing table {table_name:?} without rebuilding it"
+    );
+    String::new()
+}
+
 /// Helper for operations involving sequences
 #[cfg(feature = "postgres")]
 #[derive(Debug)]
@@ -1,5 +1,7 @@
 #[cfg(feature = "postgres")]
 use crate::constants::postgresql_type;
+#[cfg(feature = "sqlite")]
+use crate::constants::sqlite_type;
 #[cfg(feature = "mssql")]
 use crate::constants::sqlserver_type;
 use crate::constants::{regex_patterns, rust_type};
@@ -100,6 +102,44 @@ pub fn to_sqlserver_syntax(field: &CanyonRegisterEntityField) -> String {
     }
 }
 
+/// Return the SQLite datatype and parameters to create a column for a given rust type
+#[cfg(feature = "sqlite")]
+pub fn to_sqlite_syntax(field: &CanyonRegisterEntityField) -> String {
+    let rust_type_clean = field.field_type.replace(' ', "");
+
+    match rust_type_clean.as_str() {
+        rust_type::I8
+        | rust_type::U8
+        | rust_type::I16
+        | rust_type::U16
+        | rust_type::I32
+        | rust_type::U32
+        | rust_type::I64
+        | rust_type::U64 => String::from(&format!("{} NOT NULL", sqlite_type::INTEGER)),
+        rust_type::OPT_I8
+        | rust_type::OPT_U8
+        | rust_type::OPT_I16
+        | rust_type::OPT_U16
+        | rust_type::OPT_I32
+        | rust_type::OPT_U32
+        | rust_type::OPT_I64
+        | rust_type::OPT_U64 => String::from(sqlite_type::INTEGER),
+
+        rust_type::STRING
+        | rust_type::NAIVE_DATE
+        | rust_type::NAIVE_TIME
+        | rust_type::NAIVE_DATE_TIME => String::from(&format!("{} NOT NULL", sqlite_type::TEXT)),
+        rust_type::OPT_STRING
+        | rust_type::OPT_NAIVE_DATE
+        | rust_type::OPT_NAIVE_TIME
+        | rust_type::OPT_NAIVE_DATE_TIME => String::from(sqlite_type::TEXT),
+
+        rust_type::BOOL => String::from(&format!("{} NOT NULL", sqlite_type::BOOLEAN)),
+        rust_type::OPT_BOOL => String::from(sqlite_type::BOOLEAN),
+        &_ => todo!("Not supported datatype for this migrations version"),
+    }
+}
+
 #[cfg(feature = "postgres")]
 pub fn to_postgres_alter_syntax(field: &CanyonRegisterEntityField) -> String {
     let mut rust_type_clean = field.field_type.replace(' ', "");
@@ -177,3 +217,36 @@ pub fn to_sqlserver_alter_syntax(field: &CanyonRegisterEntityField) -> String {
         &_ => todo!("Not supported datatype for this migrations version"),
     }
 }
+
+#[cfg(feature = "sqlite")]
+pub fn to_sqlite_alter_syntax(field: &CanyonRegisterEntityField) -> String {
+    let mut rust_type_clean = field.field_type.replace(' ', "");
+    let rs_type_is_optional = field.field_type.to_uppercase().starts_with("OPTION");
+
+    if rs_type_is_optional {
+        let type_regex = Regex::new(regex_patterns::EXTRACT_RUST_OPT_REGEX).unwrap();
+        let capture_rust_type = type_regex.captures(rust_type_clean.as_str()).unwrap();
+        rust_type_clean = capture_rust_type
+            .name("rust_type")
+            .unwrap()
+            .as_str()
+            .to_string();
+    }
+
+    match rust_type_clean.as_str() {
+        rust_type::I8
+        | rust_type::U8
+        | rust_type::I16
+        | rust_type::U16
+        | rust_type::I32
+        | rust_type::U32
+        | rust_type::I64
+        | rust_type::U64 => String::from(sqlite_type::INTEGER),
+        rust_type::STRING
+        | rust_type::NAIVE_DATE
+        | rust_type::NAIVE_TIME
+        | rust_type::NAIVE_DATE_TIME => String::from(sqlite_type::TEXT),
+        rust_type::BOOL => String::from(sqlite_type::BOOLEAN),
+        &_ => todo!("Not supported datatype for this migrations version"),
+    }
+}
@@ -19,6 +19,12 @@ pub mod migrations {
 /// things in `Canyon-SQL`, like the `main` macro, the IT macro.
 pub use canyon_macros::main;
 
+/// Configures Canyon from the code, instead of a configuration file. See [`config::CanyonSqlConfig::builder`]
+pub use canyon_connection::init;
+
+/// Opens a new transaction against the given datasource. See [`crud::CanyonTransaction`]
+pub use canyon_crud::transaction::transaction;
+
 /// Public API for the `Canyon-SQL` proc-macros, and for the external ones
 pub mod macros {
     pub use canyon_crud::async_trait::*;
@@ -28,6 +34,11 @@ pub mod macros {
 /// connection module serves to reexport the public elements of the `canyon_connection` crate,
 /// exposing them through the public API
 pub mod connection {
+    pub use canyon_connection::pool::InitConnectionsError;
+    pub use canyon_connection::{
+        list_datasources, ping, register_datasource, unregister_datasource,
+    };
+
     #[cfg(feature = "postgres")]
     pub use canyon_connection::canyon_database_connector::DatabaseConnection::Postgres;
 
@@ -36,6 +47,27 @@ pub mod connection {
 
     #[cfg(feature = "mysql")]
     pub use canyon_connection::canyon_database_connector::DatabaseConnection::MySQL;
+
+    #[cfg(feature = "sqlite")]
+    pub use canyon_connection::canyon_database_connector::DatabaseConnection::Sqlite;
+
+    #[cfg(feature = "mock")]
+    pub use canyon_connection::canyon_database_connector::DatabaseConnection::Mock;
+}
+
+/// The in-memory database to unit test the code that uses Canyon. See [`mock::MockDatabase`]
+#[cfg(feature = "mock")]
+pub mod mock {
+    pub use canyon_connection::mock::*;
+}
+
+/// The configuration of Canyon and its datasources, to build it from the code
+pub mod config {
+    pub use canyon_connection::datasources::*;
+    pub use canyon_connection::pool::PoolProperties;
+    pub use canyon_connection::runtime::{RuntimeFlavor, RuntimeProperties};
+    pub use canyon_connection::session::{IsolationLevel, SessionProperties};
+    pub use canyon_connection::CANYON_CONFIG_ENV;
 }
 
 /// Crud module serves to reexport the public elements of the `canyon_crud` crate,
@@ -43,21 +75,30 @@ pub mod connection {
 pub mod crud {
     pub use canyon_crud::bounds;
     pub use canyon_crud::crud::*;
+    pub use canyon_crud::error::CanyonError;
     pub use canyon_crud::mapper::*;
     pub use canyon_crud::rows::CanyonRows;
+    #[cfg(feature = "sqlite")]
+    pub use canyon_crud::rows::SqliteRow;
+    pub use canyon_crud::transaction::CanyonTransaction;
     pub use canyon_crud::DatabaseType;
 }
 
 /// Re-exports the query elements from the `crud`crate
 pub mod query {
     pub use canyon_crud::query_elements::operators;
-    pub use canyon_crud::query_elements::{query::*, query_builder::*};
+    pub use canyon_crud::query_elements::{
+        aggregate::Aggregate, expression::Expr, pagination::Page, query::*, query_builder::*,
+    };
+    pub use canyon_crud::{all, any};
 }
 
 /// Reexport the available database clients within Canyon
 pub mod db_clients {
     #[cfg(feature = "mysql")]
     pub use canyon_connection::mysql_async;
+    #[cfg(feature = "sqlite")]
+    pub use canyon_connection::rusqlite;
     #[cfg(feature = "mssql")]
     pub use canyon_connection::tiberius;
     #[cfg(feature = "postgres")]
@@ -66,11 +107,12 @@ pub mod db_clients {
 
 /// Reexport the needed runtime dependencies
 pub mod runtime {
+    pub use canyon_connection::canyon_runtime;
     pub use canyon_connection::futures;
     pub use canyon_connection::init_connections_cache;
+    pub use canyon_connection::runtime::{RuntimeFlavor, RuntimeProperties};
     pub use canyon_connection::tokio;
     pub use canyon_connection::tokio_util;
-    pub use canyon_connection::CANYON_TOKIO_RUNTIME;
 }
 
 /// Module for reexport the `chrono` crate with the allowed public and available types in Canyon
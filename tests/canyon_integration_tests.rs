@@ -8,10 +8,9 @@
 /// and abort the execution.
 extern crate canyon_sql;
 
-use std::error::Error;
-
 mod crud;
 mod migrations;
 
 mod constants;
+mod fixtures;
 mod tests_models;
@@ -2,10 +2,16 @@
 
 #[cfg(feature = "postgres")]
 pub const PSQL_DS: &str = "postgres_docker";
+#[cfg(feature = "postgres")]
+pub const PSQL_TLS_DS: &str = "postgres_docker_tls";
+#[cfg(feature = "postgres")]
+pub const PSQL_URL_DS: &str = "postgres_docker_url";
 #[cfg(feature = "mssql")]
 pub const SQL_SERVER_DS: &str = "sqlserver_docker";
 #[cfg(feature = "mysql")]
 pub const MYSQL_DS: &str = "mysql_docker";
+#[cfg(feature = "sqlite")]
+pub const SQLITE_DS: &str = "sqlite_file";
 
 #[cfg(all(feature = "postgres", feature = "migrations"))]
 pub static FETCH_PUBLIC_SCHEMA: &str =
@@ -378,3 +384,46 @@ INSERT INTO dbo.tournament (id, ext_id, slug, start_date, end_date, league) VALU
 INSERT INTO dbo.tournament (id, ext_id, slug, start_date, end_date, league) VALUES (23, 107418086627198298, 'lcs_academy_2022_spring', '2022-01-19', '2022-05-31', 42);
 SET IDENTITY_INSERT dbo.tournament OFF;
 ";
+
+#[cfg(feature = "sqlite")]
+pub const SQLITE_CREATE_TABLES: &str = "
+CREATE TABLE IF NOT EXISTS league (
+    id					INTEGER PRIMARY KEY AUTOINCREMENT,
+    ext_id				BIGINT NOT NULL,
+    slug				TEXT NOT NULL,
+    name				TEXT NOT NULL,
+    region				TEXT NOT NULL,
+    image_url			TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS tournament (
+    id					INTEGER PRIMARY KEY AUTOINCREMENT,
+    ext_id				BIGINT NOT NULL,
+    slug				TEXT NOT NULL,
+    start_date			DATE NOT NULL,
+    end_date			DATE NOT NULL,
+    league				INT REFERENCES league(id)
+);
+
+CREATE TABLE IF NOT EXISTS player (
+    id					INTEGER PRIMARY KEY AUTOINCREMENT,
+    ext_id				BIGINT NOT NULL,
+    first_name			TEXT NOT NULL,
+    last_name			TEXT NOT NULL,
+    summoner_name		TEXT NOT NULL,
+    image_url			TEXT,
+    role				TEXT NOT NULL
+);
+
+CREATE TABLE IF NOT EXISTS team (
+    id					INTEGER PRIMARY KEY AUTOINCREMENT,
+    ext_id				BIGINT NOT NULL,
+    slug				TEXT NOT NULL,
+    name				TEXT NOT NULL,
+    code				TEXT NOT NULL,
+    image_url			TEXT NOT NULL,
+    alt_image_url		TEXT,
+    bg_image_url		TEXT,
+    home_league			INT REFERENCES league(id)
+);
+";
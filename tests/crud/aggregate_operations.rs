@@ -0,0 +1,174 @@
+//! Integration tests for the aggregates of the `SelectQueryBuilder`, and for the
+//! grouping of its rows with `GROUP BY` and `HAVING`
+use std::collections::BTreeMap;
+
+use canyon_sql::crud::CrudOperations;
+#[cfg(feature = "mock")]
+use canyon_sql::crud::DatabaseType;
+use canyon_sql::macros::CanyonMapper;
+#[cfg(feature = "mock")]
+use canyon_sql::mock::{MockRow, MockValue};
+use canyon_sql::query::{operators::Comp, ops::QueryBuilder, Aggregate};
+
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
+#[cfg(feature = "mock")]
+use crate::fixtures::MockDatasource;
+
+use crate::tests_models::league::*;
+
+/// The number of leagues of a region
+#[derive(Debug, CanyonMapper, PartialEq)]
+struct RegionCount {
+    region: String,
+    count: i64,
+}
+
+/// Aggregates the leagues of the datasource, and compares the results with
+/// the ones computed over all of them
+async fn check_aggregates(datasource_name: &str) {
+    let leagues = League::select_query_datasource(datasource_name)
+        .query()
+        .await
+        .expect("Failed to read the leagues");
+    let first: Vec<&League> = leagues.iter().filter(|league| league.id <= 50).collect();
+    assert!(!first.is_empty(), "There are no leagues to aggregate");
+
+    let (count, sum, min, max, avg): (i64, Option<i64>, Option<i32>, Option<i32>, Option<f64>) =
+        League::select_query_datasource(datasource_name)
+            .count()
+            .sum(LeagueField::id)
+            .min(LeagueField::id)
+            .max(LeagueField::id)
+            .avg(LeagueField::id)
+            .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+            .query_as()
+            .await
+            .expect("Failed to aggregate the leagues")
+            .remove(0);
+    let ids = first.iter().map(|league| league.id);
+    let expected_sum: i64 = ids.clone().map(i64::from).sum();
+    assert_eq!(count, first.len() as i64);
+    assert_eq!(sum, Some(expected_sum));
+    assert_eq!(min, ids.clone().min());
+    assert_eq!(max, ids.max());
+    let avg = avg.expect("The leagues have an average id");
+    assert!((avg - expected_sum as f64 / first.len() as f64).abs() < 1e-9);
+
+    let mut per_region = BTreeMap::<String, i64>::new();
+    leagues
+        .iter()
+        .for_each(|league| *per_region.entry(league.region.clone()).or_default() += 1);
+
+    let counts: Vec<RegionCount> = League::select_query_datasource(datasource_name)
+        .select(&[LeagueField::region])
+        .count()
+        .group_by(&[LeagueField::region])
+        .order_by(LeagueField::region, false)
+        .query_as()
+        .await
+        .expect("Failed to count the leagues of each region");
+    let expected: Vec<RegionCount> = per_region
+        .iter()
+        .map(|(region, count)| RegionCount {
+            region: region.clone(),
+            count: *count,
+        })
+        .collect();
+    assert_eq!(counts, expected);
+
+    // Without any other projection, the groups select their own columns
+    let regions: Vec<(String,)> = League::select_query_datasource(datasource_name)
+        .group_by(&[LeagueField::region])
+        .order_by(LeagueField::region, false)
+        .query_as()
+        .await
+        .expect("Failed to read the regions");
+    let expected: Vec<(String,)> = per_region.keys().map(|region| (region.clone(),)).collect();
+    assert_eq!(regions, expected);
+
+    // Only the regions with more than one league, counted by pages of groups
+    let page = League::select_query_datasource(datasource_name)
+        .select(&[LeagueField::region])
+        .count()
+        .group_by(&[LeagueField::region])
+        .having(Aggregate::Count, Comp::Gt, &1_i64)
+        .order_by(LeagueField::region, false)
+        .page(0, 2)
+        .query_page_as::<(String, i64)>()
+        .await
+        .expect("Failed to read the page of the regions");
+    let expected: Vec<(String, i64)> = per_region
+        .into_iter()
+        .filter(|(_, count)| *count > 1)
+        .collect();
+    assert_eq!(page.total, expected.len() as u64);
+    assert_eq!(page.items, expected[..expected.len().min(2)]);
+}
+
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_postgres_aggregates() {
+    check_aggregates(PSQL_DS).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_sqlite_aggregates() {
+    check_aggregates(SQLITE_DS).await;
+}
+
+/// SQL Server counts into a `BIGINT`, its `HAVING` goes after the groups and its
+/// placeholders are numbered after the ones of the filters
+#[cfg(all(feature = "mock", feature = "mssql"))]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_aggregates_sql_of_sqlserver() {
+    const MOCK_DS: &str = "mock_aggregates";
+
+    let database = MockDatasource::register(MOCK_DS, DatabaseType::SqlServer).await;
+
+    database.push_rows([MockRow::new([
+        ("region", MockValue::from("KOREA")),
+        ("count", MockValue::from(4_i64)),
+        ("avg_ext_id", MockValue::from(2.5)),
+    ])]);
+    database.push_rows([MockRow::new([("count", MockValue::from(1_i64))])]);
+    let page = League::select_query_datasource(MOCK_DS)
+        .select(&[LeagueField::region])
+        .count()
+        .avg(LeagueField::ext_id)
+        .having(Aggregate::Count, Comp::GtEq, &2_i64)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .group_by(&[LeagueField::region])
+        .limit(10)
+        .query_page_as::<(String, i64, Option<f64>)>()
+        .await
+        .expect("Failed to read the page of the regions");
+    assert_eq!(page.items, [(String::from("KOREA"), 4, Some(2.5))]);
+    assert_eq!(page.total, 1);
+
+    let grouped = "SELECT region, COUNT_BIG(*) AS count, AVG(CAST(ext_id AS FLOAT)) AS avg_ext_id \
+        FROM league WHERE id <= $2 GROUP BY region HAVING COUNT_BIG(*) >= $1";
+
+    let mut regions = League::select_query_datasource(MOCK_DS);
+    regions.group_by(&[LeagueField::region, LeagueField::slug]);
+    assert_eq!(
+        regions.sql(),
+        "SELECT region, slug FROM league GROUP BY region, slug"
+    );
+    let statements = database.statements();
+    let sql: Vec<&str> = statements.iter().map(|stmt| stmt.sql.as_str()).collect();
+    assert_eq!(
+        sql,
+        [
+            format!("{grouped} ORDER BY (SELECT NULL) OFFSET 0 ROWS FETCH NEXT 11 ROWS ONLY;"),
+            format!("SELECT COUNT(*) FROM ({grouped}) AS page_groups;"),
+        ]
+    );
+    assert_eq!(
+        statements[1].params,
+        [MockValue::Int(2), MockValue::Int(50)]
+    );
+}
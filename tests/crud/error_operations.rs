@@ -0,0 +1,80 @@
+//! Integration tests for the errors returned by the CRUD operations, checking that the
+//! failures reported by the database are translated into the right [`CanyonError`] kind
+use canyon_sql::crud::{CanyonError, CrudOperations};
+use canyon_sql::query::{operators::Comp, ops::QueryBuilder};
+
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
+
+use crate::tests_models::league::*;
+
+/// Querying a datasource that isn't configured is reported as a configuration error
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_error_unknown_datasource() {
+    let result = League::find_all_datasource("non_existent_datasource").await;
+
+    assert!(matches!(result, Err(CanyonError::Config(_))));
+}
+
+/// Giving to a row the primary key of another one violates its unique constraint
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_error_unique_violation() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut q = League::update_query_datasource(PSQL_DS);
+    q.set(&[(LeagueField::id, 1)])
+        .r#where(LeagueFieldValue::id(&2), Comp::Eq);
+    let result = q.query_transaction(&mut tx).await;
+
+    assert!(matches!(result, Err(CanyonError::UniqueViolation(_))));
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+}
+
+/// Deleting a league that still has tournaments violates their foreign key
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_error_foreign_key_violation() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let league = League::find_by_pk_transaction(&1, &mut tx)
+        .await
+        .expect("Failed find by pk within the transaction")
+        .expect("The league with id 1 must exist");
+    let result = league.delete_transaction(&mut tx).await;
+
+    assert!(matches!(result, Err(CanyonError::ForeignKeyViolation(_))));
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+}
+
+/// Same as the above, but the violation is reported by `SQLite`, which only checks
+/// the foreign keys when the `foreign_keys` pragma is enabled on the connection
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_error_foreign_key_violation_sqlite() {
+    let mut tx = canyon_sql::transaction(SQLITE_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let league = League::find_by_pk_transaction(&1, &mut tx)
+        .await
+        .expect("Failed find by pk within the transaction")
+        .expect("The league with id 1 must exist");
+    let result = league.delete_transaction(&mut tx).await;
+
+    assert!(matches!(result, Err(CanyonError::ForeignKeyViolation(_))));
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+}
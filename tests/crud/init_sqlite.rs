@@ -0,0 +1,43 @@
+use crate::constants::SQLITE_CREATE_TABLES;
+use crate::constants::SQLITE_DS;
+use crate::tests_models::league::League;
+
+use canyon_sql::crud::CrudOperations;
+use canyon_sql::db_clients::rusqlite::Connection;
+
+/// `SQLite` doesn't run as a service, so there's no docker container that creates
+/// and fills the tables at start-up. Instead, we create the tables on the database
+/// file of the `sqlite_file` datasource, and we fill them with the same values
+/// that the `MySQL` docker instance inserts, if they aren't already there.
+///
+/// This will be marked as `#[ignore]`, so we can force to run first the marked as
+/// ignored, check the data available, perform the necessary init operations and
+/// then *cargo test <args...>* the real integration tests
+#[canyon_sql::macros::canyon_tokio_test]
+#[ignore]
+fn initialize_sqlite_database() {
+    static FILL_TABLE_VALUES: &str = include_str!("../../docker/mysql/fill_tables.sql");
+
+    let client = Connection::open("canyon_sqlite.db").expect("Can't open the SQLite database");
+
+    // Create the tables
+    let query_result = client.execute_batch(SQLITE_CREATE_TABLES);
+    assert!(query_result.is_ok());
+
+    let leagues_sql = League::find_all_datasource(SQLITE_DS).await;
+    assert!(leagues_sql.is_ok());
+
+    match leagues_sql {
+        Ok(ref leagues) => {
+            let leagues_len = leagues.len();
+            println!("Leagues already inserted on SQLITE: {:?}", &leagues_len);
+            if leagues.len() < 10 {
+                let result = client.execute_batch(
+                    &FILL_TABLE_VALUES.replace("INSERT INTO public.", "INSERT OR IGNORE INTO "),
+                );
+                assert!(result.is_ok());
+            }
+        }
+        Err(e) => eprintln!("Error retrieving the leagues: {e}"),
+    }
+}
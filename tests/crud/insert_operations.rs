@@ -4,6 +4,8 @@ use canyon_sql::crud::CrudOperations;
 
 #[cfg(feature = "mysql")]
 use crate::constants::MYSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
 #[cfg(feature = "mssql")]
 use crate::constants::SQL_SERVER_DS;
 
@@ -121,6 +123,38 @@ fn test_crud_insert_datasource_mysql_operation() {
     assert_eq!(new_league.id, inserted_league.id);
 }
 
+/// Same as the insert operation above, but targeting the database defined in
+/// the specified datasource
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_insert_datasource_sqlite_operation() {
+    let mut new_league: League = League {
+        id: Default::default(),
+        ext_id: 7892635306594_i64,
+        slug: "some-new-league".to_string(),
+        name: "Some New League".to_string(),
+        region: "Bahía de cochinos".to_string(),
+        image_url: "https://nobodyspectsandimage.io".to_string(),
+    };
+
+    // We insert the instance on the database, on the `League` entity
+    new_league
+        .insert_datasource(SQLITE_DS)
+        .await
+        .expect("Failed insert datasource operation");
+
+    // Now, in the `id` field of the instance, we have the autogenerated
+    // value for the primary key field, which is id. So, we can query the
+    // database again with the find by primary key operation to check if
+    // the value was really inserted
+    let inserted_league = League::find_by_pk_datasource(&new_league.id, SQLITE_DS)
+        .await
+        .expect("Failed the query to the database")
+        .expect("No entity found for the primary key value passed in");
+
+    assert_eq!(new_league.id, inserted_league.id);
+}
+
 /// The multi insert operation is a shorthand for insert multiple instances of *T*
 /// in the database at once.
 ///
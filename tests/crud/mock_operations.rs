@@ -0,0 +1,130 @@
+//! Integration tests for the in-memory mock database, which records the statements
+//! generated by the CRUD operations and the query builders instead of running them
+#![cfg(all(feature = "mock", feature = "postgres"))]
+use canyon_sql::crud::{CanyonError, CrudOperations, DatabaseType};
+use canyon_sql::mock::{MockRow, MockStatement, MockValue};
+use canyon_sql::query::{operators::Comp, ops::QueryBuilder};
+
+use crate::fixtures::MockDatasource;
+use crate::tests_models::league::*;
+
+fn league_row(id: i32, slug: &str) -> MockRow {
+    MockRow::new([
+        ("id", MockValue::from(id)),
+        ("ext_id", 100695891328981_i64.into()),
+        ("slug", slug.into()),
+        ("name", "LCK".into()),
+        ("region", "KOREA".into()),
+        ("image_url", "https://lolstatic.com/lck.png".into()),
+    ])
+}
+
+/// Maps the scripted rows into the entities, and records the SQL and the parameters
+/// of every operation
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_mock_crud_operations() {
+    const MOCK_DS: &str = "mock_crud";
+    let database = MockDatasource::register(MOCK_DS, DatabaseType::PostgreSql).await;
+
+    database.push_rows([league_row(1, "lck"), league_row(2, "lck-academy")]);
+    let leagues = League::find_all_datasource(MOCK_DS)
+        .await
+        .expect("Failed to map the scripted rows");
+    assert_eq!(leagues.len(), 2);
+    assert_eq!(leagues[1].slug, "lck-academy");
+
+    database.push_rows([league_row(7, "lck")]);
+    let league = League::find_by_pk_datasource(&7, MOCK_DS)
+        .await
+        .expect("Failed to map the scripted row")
+        .expect("A league was scripted");
+    assert_eq!(league.id, 7);
+
+    database.push_rows([MockRow::new([("count", MockValue::from(42_i64))])]);
+    assert_eq!(League::count_datasource(MOCK_DS).await.unwrap(), 42);
+
+    league
+        .delete_datasource(MOCK_DS)
+        .await
+        .expect("Failed to delete the league");
+
+    // No rows were scripted, so nothing is found
+    assert_eq!(
+        League::find_by_pk_datasource(&8, MOCK_DS).await.unwrap(),
+        None
+    );
+
+    database.push_rows([MockRow::new([("id", MockValue::from("seven"))])]);
+    assert!(matches!(
+        League::find_all_datasource(MOCK_DS).await,
+        Err(CanyonError::Mapping { .. })
+    ));
+    database.push_error("connection reset by peer");
+    assert!(matches!(
+        League::find_all_datasource(MOCK_DS).await,
+        Err(CanyonError::Backend(_))
+    ));
+
+    let statements = database.statements();
+    assert_eq!(statements.len(), 7);
+    assert_eq!(
+        statements[0],
+        MockStatement {
+            sql: "SELECT * FROM league".to_string(),
+            params: Vec::new(),
+        }
+    );
+    assert_eq!(statements[1].sql, "SELECT * FROM league WHERE id = $1");
+    assert_eq!(statements[1].params, [MockValue::Int(7)]);
+    assert_eq!(statements[2].sql, "SELECT COUNT(*) FROM league");
+    assert_eq!(statements[3].sql, r#"DELETE FROM league WHERE "id" = $1"#);
+    assert_eq!(statements[3].params, [MockValue::Int(7)]);
+}
+
+/// Checks the exact SQL produced by the query builders, and the statements that
+/// delimit a transaction in the emulated dialect
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_mock_query_builders_and_transactions() {
+    const MOCK_DS: &str = "mock_query_builders";
+    let database = MockDatasource::register(MOCK_DS, DatabaseType::PostgreSql).await;
+
+    database.push_rows([league_row(3, "lck")]);
+    let leagues = League::select_query_datasource(MOCK_DS)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .and(LeagueFieldValue::region(&"KOREA"), Comp::Eq)
+        .query()
+        .await
+        .expect("Failed to map the scripted rows");
+    assert_eq!(leagues.len(), 1);
+
+    let mut tx = canyon_sql::transaction(MOCK_DS)
+        .await
+        .expect("Failed to begin the transaction");
+    League::update_query_datasource(MOCK_DS)
+        .set(&[(LeagueField::name, "LCK Challengers")])
+        .r#where(LeagueFieldValue::id(&3), Comp::Eq)
+        .query_transaction(&mut tx)
+        .await
+        .expect("Failed to update within the transaction");
+    tx.commit().await.expect("Failed to commit");
+
+    let statements = database.statements();
+    let sql: Vec<&str> = statements.iter().map(|stmt| stmt.sql.as_str()).collect();
+    assert_eq!(
+        sql,
+        [
+            "SELECT * FROM league WHERE id <= $1 AND region = $2;",
+            "BEGIN",
+            "UPDATE league SET name = $1 WHERE id = $2;",
+            "COMMIT",
+        ]
+    );
+    assert_eq!(
+        statements[0].params,
+        [MockValue::Int(50), MockValue::from("KOREA")]
+    );
+    assert_eq!(
+        statements[2].params,
+        [MockValue::from("LCK Challengers"), MockValue::Int(3)]
+    );
+}
@@ -1,10 +1,23 @@
 #![allow(unused_imports)]
 
+pub mod aggregate_operations;
+pub mod datasource_registry_operations;
 pub mod delete_operations;
+pub mod error_operations;
 pub mod foreign_key_operations;
 #[cfg(feature = "mssql")]
 pub mod init_mssql;
+#[cfg(feature = "sqlite")]
+pub mod init_sqlite;
 pub mod insert_operations;
+pub mod mock_operations;
+pub mod pagination_operations;
+pub mod projection_operations;
 pub mod querybuilder_operations;
+pub mod read_replicas_operations;
+pub mod reconnection_operations;
 pub mod select_operations;
+pub mod session_operations;
+pub mod timeout_operations;
+pub mod transaction_operations;
 pub mod update_operations;
@@ -0,0 +1,209 @@
+//! Integration tests for the pagination of the `SelectQueryBuilder`, by offset and by
+//! the keyset of a stable cursor, and for the total count of its pages
+#[cfg(feature = "mock")]
+use canyon_sql::crud::CanyonError;
+use canyon_sql::crud::CrudOperations;
+#[cfg(feature = "mock")]
+use canyon_sql::crud::DatabaseType;
+#[cfg(feature = "mock")]
+use canyon_sql::mock::{MockRow, MockValue};
+use canyon_sql::query::{operators::Comp, ops::QueryBuilder};
+
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
+#[cfg(feature = "mock")]
+use crate::fixtures::MockDatasource;
+
+use crate::tests_models::league::*;
+
+/// The leagues with an id lower or equal than 50, ordered by their id
+async fn first_leagues(datasource_name: &str) -> Vec<League> {
+    League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .query()
+        .await
+        .expect("Failed to read the leagues")
+}
+
+/// Pages through the leagues with an offset, and with the cursor of the last one read
+async fn check_pagination(datasource_name: &str) {
+    let leagues = first_leagues(datasource_name).await;
+    assert!(leagues.len() > 10, "Not enough leagues to paginate");
+
+    let limited = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .limit(3)
+        .query()
+        .await
+        .expect("Failed to limit the query");
+    assert_eq!(limited, leagues[..3]);
+
+    let second_page = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .page(1, 5)
+        .query()
+        .await
+        .expect("Failed to read the second page");
+    assert_eq!(second_page, leagues[5..10]);
+
+    let last_ones = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .offset(leagues.len() as u64 - 2)
+        .query()
+        .await
+        .expect("Failed to skip the leagues");
+    assert_eq!(last_ones, leagues[leagues.len() - 2..]);
+
+    // The cursor is ordered by its column, and it's combined with every filter
+    let after_the_fifth = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .or(LeagueFieldValue::id(&0), Comp::Lt)
+        .after(LeagueField::id, &leagues[4].id)
+        .limit(5)
+        .query()
+        .await
+        .expect("Failed to read after the cursor");
+    assert_eq!(after_the_fifth, leagues[5..10]);
+
+    let page = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .page(0, 5)
+        .query_page()
+        .await
+        .expect("Failed to read the first page");
+    assert_eq!(page.items, leagues[..5]);
+    assert_eq!(page.total, leagues.len() as u64);
+    assert!(page.has_next());
+    assert_eq!(page.pages(), Some(leagues.len().div_ceil(5) as u64));
+
+    // The last page of a cursor ends with the rows, although its offset is 0
+    let last_page = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .after(LeagueField::id, &leagues[leagues.len() - 4].id)
+        .limit(3)
+        .query_page()
+        .await
+        .expect("Failed to read the last page after the cursor");
+    assert_eq!(last_page.items, leagues[leagues.len() - 3..]);
+    assert!(!last_page.has_next());
+
+    let overflowing = League::select_query_datasource(datasource_name)
+        .page(u64::MAX, 5)
+        .query_page()
+        .await
+        .expect("Failed to read a page beyond the last one");
+    assert!(overflowing.items.is_empty());
+    assert!(!overflowing.has_next());
+}
+
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_postgres_pagination() {
+    check_pagination(PSQL_DS).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_sqlite_pagination() {
+    check_pagination(SQLITE_DS).await;
+}
+
+/// The pagination of SQL Server needs an ordered query, and MySQL can't skip rows
+/// without a limit
+#[cfg(all(feature = "mock", feature = "mssql", feature = "mysql"))]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_pagination_sql_of_every_database() {
+    const MOCK_DS: &str = "mock_pagination";
+
+    for (dialect, page_sql, cursor_sql) in [
+        (
+            DatabaseType::SqlServer,
+            "SELECT * FROM league WHERE id <= $1 ORDER BY (SELECT NULL) OFFSET 20 ROWS FETCH NEXT 11 ROWS ONLY;",
+            "SELECT * FROM league WHERE (id <= $1) AND id > $2 ORDER BY id OFFSET 0 ROWS FETCH NEXT 10 ROWS ONLY;",
+        ),
+        (
+            DatabaseType::MySQL,
+            "SELECT * FROM league WHERE id <= $1 LIMIT 11 OFFSET 20;",
+            "SELECT * FROM league WHERE (id <= $1) AND id > $2 ORDER BY id LIMIT 10;",
+        ),
+    ] {
+        let database = MockDatasource::register(MOCK_DS, dialect).await;
+
+        database.push_rows([]);
+        database.push_rows([MockRow::new([("count", MockValue::from(42_i64))])]);
+        let page = League::select_query_datasource(MOCK_DS)
+            .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+            .page(2, 10)
+            .query_page()
+            .await
+            .expect("Failed to read the page");
+        assert_eq!(page.total, 42);
+        assert_eq!(page.offset, 20);
+        assert!(!page.has_next());
+
+        League::select_query_datasource(MOCK_DS)
+            .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+            .after(LeagueField::id, &7)
+            .limit(10)
+            .query()
+            .await
+            .expect("Failed to read after the cursor");
+
+        let statements = database.statements();
+        let sql: Vec<&str> = statements.iter().map(|stmt| stmt.sql.as_str()).collect();
+        assert_eq!(
+            sql,
+            [
+                page_sql,
+                "SELECT COUNT(*) FROM league WHERE id <= $1;",
+                cursor_sql
+            ]
+        );
+        assert_eq!(statements[2].params, [MockValue::Int(50), MockValue::Int(7)]);
+    }
+}
+
+/// The rows after the cursor of a query in descending order are the lower ones,
+/// and the cursor must be on the column the query is ordered by
+#[cfg(all(feature = "mock", feature = "postgres"))]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_descending_cursor_sql() {
+    const MOCK_DS: &str = "mock_descending_cursor";
+    let database = MockDatasource::register(MOCK_DS, DatabaseType::PostgreSql).await;
+
+    database.push_rows([]);
+    League::select_query_datasource(MOCK_DS)
+        .r#where(LeagueFieldValue::id(&50), Comp::LtEq)
+        .order_by(LeagueField::id, true)
+        .after(LeagueField::id, &7)
+        .limit(10)
+        .query()
+        .await
+        .expect("Failed to read after the cursor");
+
+    let statements = database.statements();
+    assert_eq!(
+        statements[0].sql,
+        "SELECT * FROM league WHERE (id <= $1) AND id < $2 ORDER BY id DESC LIMIT 10;"
+    );
+    assert_eq!(
+        statements[0].params,
+        [MockValue::Int(50), MockValue::Int(7)]
+    );
+
+    let error = League::select_query_datasource(MOCK_DS)
+        .order_by(LeagueField::name, false)
+        .after(LeagueField::id, &7)
+        .query()
+        .await
+        .expect_err("The cursor isn't on the column the query is ordered by");
+    assert!(matches!(error, CanyonError::InvalidInput(_)));
+    assert_eq!(database.statements().len(), 1);
+}
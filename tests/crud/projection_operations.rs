@@ -0,0 +1,132 @@
+//! Integration tests for the projections of the `SelectQueryBuilder`, and for the
+//! mapping of the results of the queries into other types than the entities
+#[cfg(feature = "mock")]
+use canyon_sql::crud::{CanyonError, DatabaseType};
+use canyon_sql::crud::{CrudOperations, Transaction};
+use canyon_sql::macros::CanyonMapper;
+#[cfg(feature = "mock")]
+use canyon_sql::mock::{MockRow, MockValue};
+use canyon_sql::query::{operators::Comp, ops::QueryBuilder};
+
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
+#[cfg(feature = "mock")]
+use crate::fixtures::MockDatasource;
+
+use crate::tests_models::league::*;
+
+/// Only the columns of the leagues that are shown in a list
+#[derive(Debug, CanyonMapper, PartialEq)]
+struct LeagueSummary {
+    id: i32,
+    name: String,
+}
+
+/// Reads the same leagues as entities, as a type with some of their columns and
+/// as tuples, with the builder and with a raw statement
+async fn check_projections(datasource_name: &str) {
+    let leagues = League::select_query_datasource(datasource_name)
+        .r#where(LeagueFieldValue::id(&10), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .query()
+        .await
+        .expect("Failed to read the leagues");
+    assert!(!leagues.is_empty(), "There are no leagues to project");
+
+    let summaries: Vec<LeagueSummary> = League::select_query_datasource(datasource_name)
+        .select(&[LeagueField::id, LeagueField::name])
+        .r#where(LeagueFieldValue::id(&10), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .query_as()
+        .await
+        .expect("Failed to read the summaries of the leagues");
+    let expected: Vec<LeagueSummary> = leagues
+        .iter()
+        .map(|league| LeagueSummary {
+            id: league.id,
+            name: league.name.clone(),
+        })
+        .collect();
+    assert_eq!(summaries, expected);
+
+    let tuples: Vec<(String, i32)> = League::select_query_datasource(datasource_name)
+        .select(&[LeagueField::region, LeagueField::id])
+        .r#where(LeagueFieldValue::id(&10), Comp::LtEq)
+        .order_by(LeagueField::id, false)
+        .query_as()
+        .await
+        .expect("Failed to read the leagues as tuples");
+    let expected: Vec<(String, i32)> = leagues
+        .iter()
+        .map(|league| (league.region.clone(), league.id))
+        .collect();
+    assert_eq!(tuples, expected);
+
+    let raw: Vec<LeagueSummary> = League::query(
+        "SELECT id, name FROM league WHERE id <= 10 ORDER BY id",
+        [],
+        datasource_name,
+    )
+    .await
+    .expect("Failed to launch the raw statement")
+    .into_results_as()
+    .expect("Failed to map the rows of the raw statement");
+    assert_eq!(raw, summaries);
+
+    // The entity can't be built from some of its columns
+    let partial = League::select_query_datasource(datasource_name)
+        .select(&[LeagueField::id])
+        .query()
+        .await;
+    assert!(partial.is_err());
+}
+
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_postgres_projections() {
+    check_projections(PSQL_DS).await;
+}
+
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_sqlite_projections() {
+    check_projections(SQLITE_DS).await;
+}
+
+/// The selected columns replace the `*` of the query, and the tuples are mapped
+/// by the position of the columns
+#[cfg(all(feature = "mock", feature = "postgres"))]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_projection_sql_and_mapping() {
+    const MOCK_DS: &str = "mock_projection";
+
+    let database = MockDatasource::register(MOCK_DS, DatabaseType::PostgreSql).await;
+
+    let mut query = League::select_query_datasource(MOCK_DS);
+    query
+        .select(&[LeagueField::id, LeagueField::name])
+        .r#where(LeagueFieldValue::region(&"EUROPE"), Comp::Eq)
+        .limit(2);
+    assert_eq!(
+        query.sql(),
+        "SELECT id, name FROM league WHERE region = $1 LIMIT 2"
+    );
+
+    database.push_rows([
+        MockRow::new([("id", MockValue::from(1)), ("name", MockValue::from("LEC"))]),
+        MockRow::new([("id", MockValue::from(2)), ("name", MockValue::Null)]),
+    ]);
+    let tuples: Vec<(i32, Option<String>)> =
+        query.query_as().await.expect("Failed to map the tuples");
+    assert_eq!(tuples, [(1, Some(String::from("LEC"))), (2, None)]);
+
+    database.push_rows([MockRow::new([("id", MockValue::from(1))])]);
+    let error = League::select_query_datasource(MOCK_DS)
+        .select(&[LeagueField::id])
+        .query_as::<LeagueSummary>()
+        .await
+        .expect_err("The summary needs the name of the league");
+    assert!(matches!(error, CanyonError::Mapping { ref column, .. } if column == "name"));
+}
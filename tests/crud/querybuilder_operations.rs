@@ -1,5 +1,7 @@
 #[cfg(feature = "mysql")]
 use crate::constants::MYSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
 #[cfg(feature = "mssql")]
 use crate::constants::SQL_SERVER_DS;
 
@@ -11,7 +13,12 @@ use crate::constants::SQL_SERVER_DS;
 ///
 use canyon_sql::{
     crud::CrudOperations,
-    query::{operators::Comp, operators::Like, ops::QueryBuilder},
+    query::{
+        all, any,
+        operators::{Comp, ILike, Like, NotLike},
+        ops::QueryBuilder,
+        Expr,
+    },
 };
 
 use crate::tests_models::league::*;
@@ -106,6 +113,29 @@ fn test_crud_find_with_querybuilder_and_fulllike_datasource_mysql() {
     )
 }
 
+/// Builds a new SQL statement for retrieves entities of the `T` type, filtered
+/// with the parameters that modifies the base SQL to SELECT * FROM <entity>
+///
+/// `SQLite` has no `CONCAT` function, so the pattern is built with its `||` operator
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_with_querybuilder_and_fulllike_datasource_sqlite() {
+    // Find all the leagues with "LC" in their name
+    let mut filtered_leagues_result = League::select_query_datasource(SQLITE_DS);
+    filtered_leagues_result.r#where(LeagueFieldValue::name(&"LC"), Like::Full);
+
+    assert_eq!(
+        filtered_leagues_result.read_sql(),
+        "SELECT * FROM league WHERE name LIKE '%' || $1 || '%'"
+    );
+
+    let filtered_leagues = League::select_query_datasource(SQLITE_DS)
+        .r#where(LeagueFieldValue::name(&"LC"), Like::Full)
+        .query()
+        .await;
+    assert!(!filtered_leagues.unwrap().is_empty());
+}
+
 /// Builds a new SQL statement for retrieves entities of the `T` type, filtered
 /// with the parameters that modifies the base SQL to SELECT * FROM <entity>
 #[cfg(feature = "postgres")]
@@ -221,6 +251,19 @@ fn test_crud_find_with_querybuilder_datasource_mysql() {
     assert!(!filtered_find_players.unwrap().is_empty());
 }
 
+/// Same than the above but with the specified datasource
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_with_querybuilder_datasource_sqlite() {
+    // Find all the players where its ID column value is greater that 50
+    let filtered_find_players = Player::select_query_datasource(SQLITE_DS)
+        .r#where(PlayerFieldValue::id(&50), Comp::Gt)
+        .query()
+        .await;
+
+    assert!(!filtered_find_players.unwrap().is_empty());
+}
+
 /// Updates the values of the range on entries defined by the constraint parameters
 /// in the database entity
 #[cfg(feature = "postgres")]
@@ -455,3 +498,227 @@ fn test_order_by_clause() {
         "SELECT * FROM league WHERE name = $1 ORDER BY id"
     )
 }
+
+/// Tests for the generated SQL query after use a WHERE clause
+/// with nested groups of conditions
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_where_expr_clause() {
+    let mut l = League::select_query();
+    l.where_expr(all![
+        (LeagueFieldValue::region(&"KOREA"), Comp::Eq),
+        any![
+            (LeagueFieldValue::name(&"LCK"), Comp::Eq),
+            all![
+                (LeagueFieldValue::id(&10), Comp::Gt),
+                (LeagueFieldValue::id(&20), Comp::Lt),
+            ],
+        ],
+        (LeagueFieldValue::slug(&"lck"), Comp::Neq),
+    ])
+    .order_by(LeagueField::id, false);
+
+    assert_eq!(
+        l.read_sql(),
+        "SELECT * FROM league WHERE (region = $1 AND (name = $2 OR (id > $3 AND id < $4)) AND slug <> $5) ORDER BY id"
+    )
+}
+
+/// The placeholders of the expression are numbered after the ones of the
+/// `SET` clause, and the groups without conditions are constant
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_where_expr_clause_on_update_and_delete() {
+    let mut u = League::update_query();
+    u.set(&[(LeagueField::name, "Random")]).where_expr(all![
+        (LeagueFieldValue::id(&1), Comp::Gt),
+        any![
+            (LeagueFieldValue::region(&"KOREA"), Comp::Eq),
+            (LeagueFieldValue::region(&"CHINA"), Comp::Eq),
+        ],
+    ]);
+    assert_eq!(
+        u.read_sql(),
+        "UPDATE league SET name = $1 WHERE (id > $2 AND (region = $3 OR region = $4))"
+    );
+
+    let mut d = League::delete_query();
+    d.where_expr(any![
+        all![],
+        any![(LeagueFieldValue::id(&1), Comp::Eq)],
+        any![]
+    ]);
+    assert_eq!(
+        d.read_sql(),
+        "DELETE FROM league WHERE (1 = 1 OR id = $1 OR 1 = 0)"
+    );
+}
+
+/// A group at the root of the `WHERE` clause is enclosed in parentheses too, so the
+/// conditions chained after it don't take it apart
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_where_expr_clause_followed_by_and() {
+    let mut l = League::select_query();
+    l.where_expr(any![
+        (LeagueFieldValue::region(&"KOREA"), Comp::Eq),
+        (LeagueFieldValue::region(&"CHINA"), Comp::Eq),
+    ])
+    .and(LeagueFieldValue::id(&10), Comp::Gt)
+    .or_expr(all![(LeagueFieldValue::slug(&"lck"), Comp::Eq)]);
+
+    assert_eq!(
+        l.read_sql(),
+        "SELECT * FROM league WHERE (region = $1 OR region = $2) AND id > $3 OR slug = $4"
+    )
+}
+
+/// The nested groups keep their meaning against the database, instead of
+/// depending on the precedence of `AND` over `OR`
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_with_where_expr() {
+    let expected: Vec<i32> = League::find_all()
+        .await
+        .expect("Failed to retrieve the leagues")
+        .into_iter()
+        .filter(|league| league.region == "KOREA" && (league.id <= 50 || league.slug == "lck"))
+        .map(|league| league.id)
+        .collect();
+    assert!(!expected.is_empty());
+
+    let filtered_leagues = League::select_query()
+        .where_expr(all![
+            (LeagueFieldValue::region(&"KOREA"), Comp::Eq),
+            any![
+                (LeagueFieldValue::id(&50), Comp::LtEq),
+                (LeagueFieldValue::slug(&"lck"), Comp::Eq),
+            ],
+        ])
+        .order_by(LeagueField::id, false)
+        .query()
+        .await
+        .expect("Failed to filter the leagues");
+
+    let mut found: Vec<i32> = filtered_leagues.iter().map(|league| league.id).collect();
+    found.sort();
+    let mut expected = expected;
+    expected.sort();
+    assert_eq!(found, expected);
+}
+
+/// Tests for the generated SQL query after use the operators without
+/// a parameter, or with several of them
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_null_between_and_in_clauses() {
+    let mut p = Player::select_query();
+    p.where_in(PlayerField::id, &[1, 7, 10])
+        .and_expr(Expr::is_not_null(PlayerField::image_url))
+        .and_expr(any![
+            Expr::between(PlayerField::id, &2, &8),
+            Expr::values_not_in(PlayerField::role, &["top", "jungle"]),
+        ])
+        .or_expr(Expr::is_null(PlayerField::summoner_name));
+
+    assert_eq!(
+        p.read_sql(),
+        "SELECT * FROM player WHERE id IN ($1, $2, $3) AND image_url IS NOT NULL AND (id BETWEEN $4 AND $5 OR role NOT IN ($6, $7)) OR summoner_name IS NULL"
+    );
+
+    let mut empty = Player::select_query();
+    empty.where_in(PlayerField::id, &[] as &[i32]);
+    assert_eq!(empty.read_sql(), "SELECT * FROM player WHERE 1 = 0");
+}
+
+/// Filters with the negated and the case-insensitive operators
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_with_the_extended_operators() {
+    let leagues = League::find_all()
+        .await
+        .expect("Failed to retrieve the leagues");
+
+    let ids: Vec<i32> = leagues.iter().take(3).map(|league| league.id).collect();
+    let not_in = League::select_query()
+        .where_expr(Expr::values_not_in(LeagueField::id, &ids))
+        .query()
+        .await
+        .expect("Failed to filter with NOT IN");
+    assert_eq!(not_in.len(), leagues.len() - 3);
+    assert!(not_in.iter().all(|league| !ids.contains(&league.id)));
+
+    let between = League::select_query()
+        .where_expr(Expr::between(LeagueField::id, &10, &20))
+        .query()
+        .await
+        .expect("Failed to filter with BETWEEN");
+    assert_eq!(
+        between.len(),
+        leagues
+            .iter()
+            .filter(|league| (10..=20).contains(&league.id))
+            .count()
+    );
+
+    let case_insensitive = League::select_query()
+        .r#where(LeagueFieldValue::region(&"korea"), ILike::Full)
+        .query()
+        .await
+        .expect("Failed to filter with ILIKE");
+    let in_korea = leagues
+        .iter()
+        .filter(|league| league.region.to_lowercase().contains("korea"))
+        .count();
+    assert!(in_korea > 0);
+    assert_eq!(case_insensitive.len(), in_korea);
+
+    let not_like = League::select_query()
+        .r#where(LeagueFieldValue::region(&"KOREA"), NotLike::Full)
+        .query()
+        .await
+        .expect("Failed to filter with NOT LIKE");
+    assert_eq!(
+        not_like.len(),
+        leagues
+            .iter()
+            .filter(|league| !league.region.contains("KOREA"))
+            .count()
+    );
+
+    let players = Player::find_all()
+        .await
+        .expect("Failed to retrieve the players")
+        .len();
+    let with_image = Player::select_query()
+        .where_expr(Expr::is_not_null(PlayerField::image_url))
+        .query()
+        .await
+        .expect("Failed to filter with IS NOT NULL")
+        .len();
+    let without_image = Player::select_query()
+        .where_expr(Expr::is_null(PlayerField::image_url))
+        .query()
+        .await
+        .expect("Failed to filter with IS NULL")
+        .len();
+    assert_eq!(with_image + without_image, players);
+}
+
+/// The `LIKE` of SQLite ignores the case of the ASCII characters
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_with_the_extended_operators_datasource_sqlite() {
+    let lowercase = League::select_query_datasource(SQLITE_DS)
+        .r#where(LeagueFieldValue::region(&"korea"), ILike::Full)
+        .query()
+        .await
+        .expect("Failed to filter with the case-insensitive LIKE");
+    assert!(!lowercase.is_empty());
+
+    let between = League::select_query_datasource(SQLITE_DS)
+        .where_in(LeagueField::id, &[1, 2, 3])
+        .and_expr(Expr::between(LeagueField::id, &2, &3))
+        .query()
+        .await
+        .expect("Failed to filter with IN and BETWEEN");
+    assert!(between
+        .iter()
+        .all(|league| league.id == 2 || league.id == 3));
+}
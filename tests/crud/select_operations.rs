@@ -3,12 +3,17 @@
 #[cfg(feature = "mysql")]
 use crate::constants::MYSQL_DS;
 
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_TLS_DS;
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_URL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
 #[cfg(feature = "mssql")]
 use crate::constants::SQL_SERVER_DS;
 // Integration tests for the CRUD operations available in `Canyon` that
 /// generates and executes *SELECT* statements
-use crate::Error;
-use canyon_sql::crud::CrudOperations;
+use canyon_sql::crud::{CanyonError, CrudOperations};
 
 use crate::tests_models::league::*;
 use crate::tests_models::player::*;
@@ -19,15 +24,13 @@ use crate::tests_models::player::*;
 #[cfg(feature = "postgres")]
 #[canyon_sql::macros::canyon_tokio_test]
 fn test_crud_find_all() {
-    let find_all_result: Result<Vec<League>, Box<dyn Error + Send + Sync>> =
-        League::find_all().await;
+    let find_all_result: Result<Vec<League>, CanyonError> = League::find_all().await;
 
     // Connection doesn't return an error
     assert!(!find_all_result.is_err());
     assert!(!find_all_result.unwrap().is_empty());
 
-    let find_all_players: Result<Vec<Player>, Box<dyn Error + Send + Sync>> =
-        Player::find_all().await;
+    let find_all_players: Result<Vec<Player>, CanyonError> = Player::find_all().await;
     assert!(!find_all_players.unwrap().is_empty());
 }
 
@@ -46,7 +49,7 @@ fn test_crud_find_all_unchecked() {
 #[cfg(feature = "mssql")]
 #[canyon_sql::macros::canyon_tokio_test]
 fn test_crud_find_all_datasource_mssql() {
-    let find_all_result: Result<Vec<League>, Box<dyn Error + Send + Sync>> =
+    let find_all_result: Result<Vec<League>, CanyonError> =
         League::find_all_datasource(SQL_SERVER_DS).await;
     // Connection doesn't return an error
     assert!(!find_all_result.is_err());
@@ -56,13 +59,27 @@ fn test_crud_find_all_datasource_mssql() {
 #[cfg(feature = "mysql")]
 #[canyon_sql::macros::canyon_tokio_test]
 fn test_crud_find_all_datasource_mysql() {
-    let find_all_result: Result<Vec<League>, Box<dyn Error + Send + Sync>> =
+    let find_all_result: Result<Vec<League>, CanyonError> =
         League::find_all_datasource(MYSQL_DS).await;
     // Connection doesn't return an error
     assert!(!find_all_result.is_err());
     assert!(!find_all_result.unwrap().is_empty());
 }
 
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_all_datasource_sqlite() {
+    let find_all_result: Result<Vec<League>, CanyonError> =
+        League::find_all_datasource(SQLITE_DS).await;
+    // Connection doesn't return an error
+    assert!(!find_all_result.is_err());
+    assert!(!find_all_result.unwrap().is_empty());
+
+    let find_all_players: Result<Vec<Player>, CanyonError> =
+        Player::find_all_datasource(SQLITE_DS).await;
+    assert!(!find_all_players.unwrap().is_empty());
+}
+
 /// Same as the `find_all_datasource()`, but with the unchecked variant and the specified dataosource,
 /// returning directly `Vec<T>` and not `Result<Vec<T>, Err>`
 #[cfg(feature = "mssql")]
@@ -79,8 +96,7 @@ fn test_crud_find_all_unchecked_datasource() {
 #[cfg(feature = "postgres")]
 #[canyon_sql::macros::canyon_tokio_test]
 fn test_crud_find_by_pk() {
-    let find_by_pk_result: Result<Option<League>, Box<dyn Error + Send + Sync>> =
-        League::find_by_pk(&1).await;
+    let find_by_pk_result: Result<Option<League>, CanyonError> = League::find_by_pk(&1).await;
     assert!(find_by_pk_result.as_ref().unwrap().is_some());
 
     let some_league = find_by_pk_result.unwrap().unwrap();
@@ -102,7 +118,7 @@ fn test_crud_find_by_pk() {
 #[cfg(feature = "mssql")]
 #[canyon_sql::macros::canyon_tokio_test]
 fn test_crud_find_by_pk_datasource_mssql() {
-    let find_by_pk_result: Result<Option<League>, Box<dyn Error + Send + Sync>> =
+    let find_by_pk_result: Result<Option<League>, CanyonError> =
         League::find_by_pk_datasource(&27, SQL_SERVER_DS).await;
     assert!(find_by_pk_result.as_ref().unwrap().is_some());
 
@@ -125,7 +141,7 @@ fn test_crud_find_by_pk_datasource_mssql() {
 #[cfg(feature = "mysql")]
 #[canyon_sql::macros::canyon_tokio_test]
 fn test_crud_find_by_pk_datasource_mysql() {
-    let find_by_pk_result: Result<Option<League>, Box<dyn Error + Send + Sync>> =
+    let find_by_pk_result: Result<Option<League>, CanyonError> =
         League::find_by_pk_datasource(&27, MYSQL_DS).await;
     assert!(find_by_pk_result.as_ref().unwrap().is_some());
 
@@ -141,6 +157,29 @@ fn test_crud_find_by_pk_datasource_mysql() {
     );
 }
 
+/// Tests the behaviour of a SELECT * FROM {table_name} WHERE <pk> = <pk_value>, where the pk is
+/// defined with the #[primary_key] attribute over some field of the type.
+///
+/// Uses the *specified datasource sqlite* in the second parameter of the function call.
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_by_pk_datasource_sqlite() {
+    let find_by_pk_result: Result<Option<League>, CanyonError> =
+        League::find_by_pk_datasource(&27, SQLITE_DS).await;
+    assert!(find_by_pk_result.as_ref().unwrap().is_some());
+
+    let some_league = find_by_pk_result.unwrap().unwrap();
+    assert_eq!(some_league.id, 27);
+    assert_eq!(some_league.ext_id, 107898214974993351_i64);
+    assert_eq!(some_league.slug, "college_championship");
+    assert_eq!(some_league.name, "College Championship");
+    assert_eq!(some_league.region, "NORTH AMERICA");
+    assert_eq!(
+        some_league.image_url,
+        "http://static.lolesports.com/leagues/1646396098648_CollegeChampionshiplogo.png"
+    );
+}
+
 /// Counts how many rows contains an entity on the target database.
 #[cfg(feature = "postgres")]
 #[canyon_sql::macros::canyon_tokio_test]
@@ -175,3 +214,79 @@ fn test_crud_count_datasource_operation_mysql() {
         League::count_datasource(MYSQL_DS).await.unwrap()
     );
 }
+
+/// Counts how many rows contains an entity on the target database using
+/// the specified datasource sqlite
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_count_datasource_operation_sqlite() {
+    assert_eq!(
+        League::find_all_datasource(SQLITE_DS).await.unwrap().len() as i64,
+        League::count_datasource(SQLITE_DS).await.unwrap()
+    );
+}
+
+/// Launches several slow queries at the same time against the *default datasource*.
+///
+/// Every query checks out its own connection of the pool, so they must run in parallel
+/// instead of waiting one for another
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_concurrent_queries_on_the_connection_pool() {
+    use canyon_sql::crud::Transaction;
+    use canyon_sql::runtime::futures;
+
+    let start = std::time::Instant::now();
+    let (first, second, third) = futures::join!(
+        League::query("SELECT pg_sleep(1)", [], ""),
+        League::query("SELECT pg_sleep(1)", [], ""),
+        League::query("SELECT pg_sleep(1)", [], "")
+    );
+
+    assert!(first.is_ok() && second.is_ok() && third.is_ok());
+    assert!(start.elapsed() < std::time::Duration::from_secs(3));
+}
+
+/// Queries the *postgres_docker_tls* datasource, whose `sslmode = 'require'` makes its
+/// connections negotiate TLS with the server, and checks that the session is encrypted
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_all_over_tls_datasource() {
+    use canyon_sql::crud::Transaction;
+
+    let find_all_result: Result<Vec<League>, CanyonError> =
+        League::find_all_datasource(PSQL_TLS_DS).await;
+    assert!(!find_all_result.unwrap().is_empty());
+
+    let ssl_status = League::query(
+        "SELECT ssl FROM pg_stat_ssl WHERE pid = pg_backend_pid()",
+        [],
+        PSQL_TLS_DS,
+    )
+    .await
+    .expect("Failed to query the TLS status of the connection");
+    let is_encrypted: bool = ssl_status.get_postgres_rows()[0].get(0);
+    assert!(is_encrypted);
+}
+
+/// Same as the `find_all_datasource()`, but against a datasource configured with a connection
+/// URL, whose extra parameters must reach the server
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_crud_find_all_url_datasource() {
+    use canyon_sql::crud::Transaction;
+
+    let find_all_result: Result<Vec<League>, CanyonError> =
+        League::find_all_datasource(PSQL_URL_DS).await;
+    assert!(!find_all_result.unwrap().is_empty());
+
+    let application_name = League::query(
+        "SELECT current_setting('application_name')",
+        [],
+        PSQL_URL_DS,
+    )
+    .await
+    .expect("Failed to query the application name of the connection");
+    let application_name: String = application_name.get_postgres_rows()[0].get(0);
+    assert_eq!(application_name, "canyon_tests");
+}
@@ -0,0 +1,181 @@
+//! Integration tests for the timeouts of the queries, which cancel the statements that
+//! run for too long on the database
+use std::time::{Duration, Instant};
+
+use canyon_sql::crud::{CanyonError, CrudOperations, Transaction};
+use canyon_sql::query::ops::QueryBuilder;
+
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "sqlite")]
+use crate::constants::SQLITE_DS;
+
+use crate::tests_models::league::*;
+
+/// The statement is cancelled on the server once the timeout expires, and the pool
+/// keeps serving queries after closing the connection of the cancelled one
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_postgres_query_timeout_cancels_the_statement() {
+    let mut select = League::select_query_datasource(PSQL_DS);
+    select.push_sql(", pg_sleep(30)");
+
+    let started = Instant::now();
+    let result = select.timeout(Duration::from_millis(300)).query().await;
+
+    assert!(
+        matches!(result, Err(CanyonError::QueryTimeout { timeout }) if timeout == Duration::from_millis(300)),
+        "{result:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    // The cancellation is processed by the server on its own, so it's given some time
+    let running = "SELECT count(*) FROM pg_stat_activity \
+        WHERE state = 'active' AND query LIKE '%pg_sleep(30)%' AND pid <> pg_backend_pid()";
+    let mut still_running = i64::MAX;
+    for _ in 0..20 {
+        let rows = League::query(running, [], PSQL_DS)
+            .await
+            .expect("Failed to look for the cancelled statement");
+        still_running = rows.get_postgres_rows()[0].get(0);
+        if still_running == 0 {
+            break;
+        }
+        canyon_sql::runtime::tokio::time::sleep(Duration::from_millis(100)).await;
+    }
+    assert_eq!(still_running, 0, "The statement wasn't cancelled");
+
+    let leagues = League::select_query_datasource(PSQL_DS)
+        .timeout(Duration::from_secs(10))
+        .query()
+        .await
+        .expect("The queries that finish in time aren't affected");
+    assert!(!leagues.is_empty());
+}
+
+/// SQLite runs the statement in the application, so it's interrupted by SQLite itself
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_sqlite_query_timeout_interrupts_the_statement() {
+    let mut select = League::select_query_datasource(SQLITE_DS);
+    select.push_sql(
+        " WHERE (WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c LIMIT 1000000000) \
+        SELECT count(*) FROM c) > 0",
+    );
+
+    let started = Instant::now();
+    let result = select.timeout(Duration::from_millis(200)).query().await;
+
+    assert!(
+        matches!(result, Err(CanyonError::QueryTimeout { .. })),
+        "{result:?}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(5));
+
+    League::find_all_datasource(SQLITE_DS)
+        .await
+        .expect("The connection is still usable after the interruption");
+}
+
+/// SQLite runs the statement away from the threads of the runtime, so the other
+/// futures keep making progress while it runs
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_sqlite_statement_doesnt_block_the_runtime() {
+    let mut select = League::select_query_datasource(SQLITE_DS);
+    select.push_sql(
+        " WHERE (WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c LIMIT 1000000000) \
+        SELECT count(*) FROM c) > 0",
+    );
+
+    let started = Instant::now();
+    let (result, slept) = canyon_sql::runtime::tokio::join!(
+        select.timeout(Duration::from_millis(500)).query(),
+        async {
+            canyon_sql::runtime::tokio::time::sleep(Duration::from_millis(50)).await;
+            started.elapsed()
+        }
+    );
+
+    assert!(
+        matches!(result, Err(CanyonError::QueryTimeout { .. })),
+        "{result:?}"
+    );
+    assert!(slept < Duration::from_millis(400), "{slept:?}");
+}
+
+/// A statement cancelled by its timeout aborts the transaction, which can only be
+/// rolled back, while the next transactions get a working connection
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_postgres_query_timeout_aborts_the_transaction() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+    let mut select = League::select_query_datasource(PSQL_DS);
+    select.push_sql(", pg_sleep(30)");
+    let result = select
+        .timeout(Duration::from_millis(300))
+        .query_transaction(&mut tx)
+        .await;
+    assert!(
+        matches!(result, Err(CanyonError::QueryTimeout { .. })),
+        "{result:?}"
+    );
+
+    let result = League::count_transaction(&mut tx).await;
+    assert!(
+        matches!(result, Err(CanyonError::TransactionAborted)),
+        "{result:?}"
+    );
+    let result = tx.commit().await;
+    assert!(
+        matches!(result, Err(CanyonError::TransactionAborted)),
+        "{result:?}"
+    );
+
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin a new transaction");
+    League::count_transaction(&mut tx)
+        .await
+        .expect("The new transaction isn't affected");
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+}
+
+/// The interruption of SQLite aborts the transaction too, and its rollback succeeds
+#[cfg(feature = "sqlite")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_sqlite_query_timeout_aborts_the_transaction() {
+    let mut tx = canyon_sql::transaction(SQLITE_DS)
+        .await
+        .expect("Failed to begin the transaction");
+    let mut select = League::select_query_datasource(SQLITE_DS);
+    select.push_sql(
+        " WHERE (WITH RECURSIVE c(x) AS (SELECT 1 UNION ALL SELECT x + 1 FROM c LIMIT 1000000000) \
+        SELECT count(*) FROM c) > 0",
+    );
+    let result = select
+        .timeout(Duration::from_millis(200))
+        .query_transaction(&mut tx)
+        .await;
+    assert!(
+        matches!(result, Err(CanyonError::QueryTimeout { .. })),
+        "{result:?}"
+    );
+
+    let result = tx.savepoint("after_timeout").await;
+    assert!(
+        matches!(result, Err(CanyonError::TransactionAborted)),
+        "{result:?}"
+    );
+    tx.rollback()
+        .await
+        .expect("An aborted transaction can be rolled back");
+
+    League::find_all_datasource(SQLITE_DS)
+        .await
+        .expect("The datasource keeps serving queries");
+}
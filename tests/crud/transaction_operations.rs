@@ -0,0 +1,402 @@
+//! Integration tests for the database transactions available in `Canyon`, where
+//! several CRUD operations are committed or discarded together
+use canyon_sql::crud::CrudOperations;
+use canyon_sql::query::{operators::Comp, ops::QueryBuilder};
+
+#[cfg(feature = "mysql")]
+use crate::constants::MYSQL_DS;
+#[cfg(feature = "postgres")]
+use crate::constants::PSQL_DS;
+#[cfg(feature = "mssql")]
+use crate::constants::SQL_SERVER_DS;
+
+use crate::tests_models::league::*;
+
+fn new_league(slug: &str) -> League {
+    League {
+        id: Default::default(),
+        ext_id: 7892635306594_i64,
+        slug: slug.to_string(),
+        name: "Some Transactional League".to_string(),
+        region: "Bahía de cochinos".to_string(),
+        image_url: "https://nobodyspectsandimage.io".to_string(),
+    }
+}
+
+/// Inserts, updates and deletes some records within the same transaction, and checks
+/// that the changes are visible inside it, and outside of it only once committed
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_commit() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut first_league = new_league("tx-committed-league");
+    let mut second_league = new_league("tx-committed-league-2");
+    League::multi_insert_transaction(&mut [&mut first_league, &mut second_league], &mut tx)
+        .await
+        .expect("Failed multi insert within the transaction");
+
+    first_league.name = "Some Committed League".to_string();
+    first_league
+        .update_transaction(&mut tx)
+        .await
+        .expect("Failed update within the transaction");
+    second_league
+        .delete_transaction(&mut tx)
+        .await
+        .expect("Failed delete within the transaction");
+
+    let inside_tx = League::find_by_pk_transaction(&first_league.id, &mut tx)
+        .await
+        .expect("Failed find by pk within the transaction")
+        .expect("The inserted league must be visible within its transaction");
+    assert_eq!(inside_tx.name, "Some Committed League");
+    assert_eq!(
+        League::find_by_pk_datasource(&first_league.id, PSQL_DS)
+            .await
+            .expect("Failed find by pk outside the transaction"),
+        None
+    );
+
+    tx.commit().await.expect("Failed to commit the transaction");
+
+    assert_eq!(
+        League::find_by_pk_datasource(&first_league.id, PSQL_DS)
+            .await
+            .expect("Failed find by pk after the commit")
+            .map(|league| league.name),
+        Some("Some Committed League".to_string())
+    );
+    assert_eq!(
+        League::find_by_pk_datasource(&second_league.id, PSQL_DS)
+            .await
+            .expect("Failed find by pk after the commit"),
+        None
+    );
+}
+
+/// Checks that nothing done within a transaction survives an explicit rollback,
+/// including the queries launched through the query builders
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_rollback() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+    let count_before = League::count_transaction(&mut tx).await.unwrap();
+
+    let mut league = new_league("tx-rolled-back-league");
+    league
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the transaction");
+
+    let mut q = League::update_query_datasource(PSQL_DS);
+    q.set(&[(LeagueField::name, "Renamed within the transaction")])
+        .r#where(LeagueFieldValue::id(&league.id), Comp::Eq);
+    q.query_transaction(&mut tx)
+        .await
+        .expect("Failed update query builder within the transaction");
+
+    let renamed = League::select_query_datasource(PSQL_DS)
+        .r#where(LeagueFieldValue::id(&league.id), Comp::Eq)
+        .query_transaction(&mut tx)
+        .await
+        .expect("Failed select query builder within the transaction");
+    assert_eq!(renamed[0].name, "Renamed within the transaction");
+    assert_eq!(
+        League::count_transaction(&mut tx).await.unwrap(),
+        count_before + 1
+    );
+
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+
+    assert_eq!(
+        League::find_by_pk_datasource(&league.id, PSQL_DS)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+/// A transaction that is dropped without being committed must be rolled back
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_rollback_on_drop() {
+    let mut league = new_league("tx-dropped-league");
+    {
+        let mut tx = canyon_sql::transaction(PSQL_DS)
+            .await
+            .expect("Failed to begin the transaction");
+        league
+            .insert_transaction(&mut tx)
+            .await
+            .expect("Failed insert within the transaction");
+    }
+
+    assert_eq!(
+        League::find_by_pk_datasource(&league.id, PSQL_DS)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+/// Savepoints discard only the changes made after them, keeping the previous ones
+/// and the ones made in the released savepoints as part of the transaction
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_savepoints() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut kept = new_league("tx-savepoint-kept-league");
+    kept.insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the transaction");
+
+    tx.savepoint("outer_unit")
+        .await
+        .expect("Failed to set the outer savepoint");
+    let mut released = new_league("tx-savepoint-released-league");
+    released
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the outer savepoint");
+
+    tx.savepoint("inner_unit")
+        .await
+        .expect("Failed to set the inner savepoint");
+    let mut discarded = new_league("tx-savepoint-discarded-league");
+    discarded
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the inner savepoint");
+
+    tx.rollback_to("inner_unit")
+        .await
+        .expect("Failed to rollback to the inner savepoint");
+    tx.release("outer_unit")
+        .await
+        .expect("Failed to release the outer savepoint");
+
+    assert!(League::find_by_pk_transaction(&released.id, &mut tx)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        League::find_by_pk_transaction(&discarded.id, &mut tx)
+            .await
+            .unwrap(),
+        None
+    );
+
+    tx.commit().await.expect("Failed to commit the transaction");
+
+    assert!(League::find_by_pk_datasource(&kept.id, PSQL_DS)
+        .await
+        .unwrap()
+        .is_some());
+    assert!(League::find_by_pk_datasource(&released.id, PSQL_DS)
+        .await
+        .unwrap()
+        .is_some());
+    assert_eq!(
+        League::find_by_pk_datasource(&discarded.id, PSQL_DS)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+/// Only plain identifiers are accepted as savepoint names
+#[cfg(feature = "postgres")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_invalid_savepoint_name() {
+    let mut tx = canyon_sql::transaction(PSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    assert!(tx.savepoint("unit; DROP TABLE league").await.is_err());
+    assert!(tx.savepoint("1st_unit").await.is_err());
+    assert!(tx.savepoint("").await.is_err());
+
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+}
+
+/// Same as the commit test above, but targeting the SQL Server datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_commit_datasource_mssql() {
+    let mut tx = canyon_sql::transaction(SQL_SERVER_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut league = new_league("tx-committed-league");
+    league
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the transaction");
+    assert!(League::find_by_pk_transaction(&league.id, &mut tx)
+        .await
+        .unwrap()
+        .is_some());
+
+    tx.commit().await.expect("Failed to commit the transaction");
+
+    assert!(League::find_by_pk_datasource(&league.id, SQL_SERVER_DS)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+/// Same as the rollback test above, but targeting the SQL Server datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_rollback_datasource_mssql() {
+    let mut tx = canyon_sql::transaction(SQL_SERVER_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut league = new_league("tx-rolled-back-league");
+    league
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the transaction");
+
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+
+    assert_eq!(
+        League::find_by_pk_datasource(&league.id, SQL_SERVER_DS)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+/// Same as the commit test above, but targeting the MySQL datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_commit_datasource_mysql() {
+    let mut tx = canyon_sql::transaction(MYSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut league = new_league("tx-committed-league");
+    league
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the transaction");
+    assert!(League::find_by_pk_transaction(&league.id, &mut tx)
+        .await
+        .unwrap()
+        .is_some());
+
+    tx.commit().await.expect("Failed to commit the transaction");
+
+    assert!(League::find_by_pk_datasource(&league.id, MYSQL_DS)
+        .await
+        .unwrap()
+        .is_some());
+}
+
+/// Same as the rollback test above, but targeting the MySQL datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_rollback_datasource_mysql() {
+    let mut tx = canyon_sql::transaction(MYSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    let mut league = new_league("tx-rolled-back-league");
+    league
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the transaction");
+
+    tx.rollback()
+        .await
+        .expect("Failed to rollback the transaction");
+
+    assert_eq!(
+        League::find_by_pk_datasource(&league.id, MYSQL_DS)
+            .await
+            .unwrap(),
+        None
+    );
+}
+
+/// Same as the savepoints test above, but targeting the SQL Server datasource
+#[cfg(feature = "mssql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_savepoints_datasource_mssql() {
+    let mut tx = canyon_sql::transaction(SQL_SERVER_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    tx.savepoint("outer_unit")
+        .await
+        .expect("Failed to set the savepoint");
+    let mut discarded = new_league("tx-savepoint-discarded-league");
+    discarded
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the savepoint");
+    tx.rollback_to("outer_unit")
+        .await
+        .expect("Failed to rollback to the savepoint");
+    tx.release("outer_unit")
+        .await
+        .expect("Failed to release the savepoint");
+
+    assert_eq!(
+        League::find_by_pk_transaction(&discarded.id, &mut tx)
+            .await
+            .unwrap(),
+        None
+    );
+
+    tx.commit().await.expect("Failed to commit the transaction");
+}
+
+/// Same as the savepoints test above, but targeting the MySQL datasource
+#[cfg(feature = "mysql")]
+#[canyon_sql::macros::canyon_tokio_test]
+fn test_transaction_savepoints_datasource_mysql() {
+    let mut tx = canyon_sql::transaction(MYSQL_DS)
+        .await
+        .expect("Failed to begin the transaction");
+
+    tx.savepoint("outer_unit")
+        .await
+        .expect("Failed to set the savepoint");
+    let mut discarded = new_league("tx-savepoint-discarded-league");
+    discarded
+        .insert_transaction(&mut tx)
+        .await
+        .expect("Failed insert within the savepoint");
+    tx.rollback_to("outer_unit")
+        .await
+        .expect("Failed to rollback to the savepoint");
+    tx.release("outer_unit")
+        .await
+        .expect("Failed to release the savepoint");
+
+    assert_eq!(
+        League::find_by_pk_transaction(&discarded.id, &mut tx)
+            .await
+            .unwrap(),
+        None
+    );
+
+    tx.commit().await.expect("Failed to commit the transaction");
+}
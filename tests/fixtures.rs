@@ -0,0 +1,44 @@
+//! Fixtures to share across the integration tests
+#![cfg(feature = "mock")]
+use std::ops::Deref;
+
+use canyon_sql::config::DatasourceConfig;
+use canyon_sql::connection::{register_datasource, unregister_datasource};
+use canyon_sql::crud::DatabaseType;
+use canyon_sql::mock::MockDatabase;
+
+/// A [`MockDatabase`] registered as a datasource, which is unregistered when
+/// dropped, even if the test panics before
+pub struct MockDatasource {
+    name: String,
+    database: MockDatabase,
+}
+
+impl MockDatasource {
+    /// Registers a new mock database, which emulates the given dialect, under the given name
+    pub async fn register(name: &str, dialect: DatabaseType) -> Self {
+        let database = MockDatabase::new(dialect);
+        register_datasource(DatasourceConfig::mock(name, database.clone()))
+            .await
+            .expect("Failed to register the mock datasource");
+        Self {
+            name: name.to_string(),
+            database,
+        }
+    }
+}
+
+impl Deref for MockDatasource {
+    type Target = MockDatabase;
+
+    fn deref(&self) -> &Self::Target {
+        &self.database
+    }
+}
+
+impl Drop for MockDatasource {
+    fn drop(&mut self) {
+        // Panicking again while a failed test unwinds would abort the rest of them
+        let _ = unregister_datasource(&self.name);
+    }
+}